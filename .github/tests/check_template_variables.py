@@ -0,0 +1,224 @@
+#!/usr/bin/env python3
+"""
+Template variable consistency checker for cargo-generate templates.
+
+Cross-references the Liquid variables used by the template against the ones it defines:
+- Placeholders in `cargo-generate.toml`, including conditional ones
+- `variable::set` calls in the rhai hooks
+- Variables built into cargo-generate, like `crate_name`
+
+Fails on variables that are used but never defined, as cargo-generate silently renders those
+as empty (e.g. `no-std-by-default` instead of `no_std-by-default` skips the whole branch).
+Also fails on defined variables that nothing uses.
+
+Run locally with:
+    python3 .github/tests/check_template_variables.py
+"""
+
+import argparse
+import difflib
+import logging
+import re
+import sys
+from pathlib import Path
+from typing import Dict, Set
+
+# Import TOML parser (tomllib for Python 3.11+, tomli for older versions)
+if sys.version_info >= (3, 11):
+    import tomllib
+else:
+    try:
+        import tomli as tomllib
+    except ImportError:
+        print("Error: tomli package required for Python <3.11. Install with: pip install tomli", file=sys.stderr)
+        sys.exit(1)
+
+# Configure logging
+logging.basicConfig(
+    level=logging.INFO,
+    format='%(levelname)s: %(message)s'
+)
+logger = logging.getLogger(__name__)
+
+# Set by cargo-generate itself, see "Builtin placeholders" in the cargo-generate book.
+BUILTIN_VARIABLES = {
+    "authors", "crate_name", "crate_type", "os-arch", "project-name",
+    "username", "within_cargo_project", "is_init",
+}
+
+# Words inside Liquid tags that aren't variables.
+LIQUID_KEYWORDS = {
+    "if", "elsif", "else", "endif", "unless", "endunless", "case", "when", "endcase",
+    "for", "in", "endfor", "break", "continue", "assign", "capture", "endcapture",
+    "raw", "endraw", "comment", "endcomment", "include", "render", "increment", "decrement",
+    "and", "or", "contains", "true", "false", "nil", "null", "empty", "blank",
+    "limit", "offset", "reversed", "forloop", "cycle", "tablerow", "endtablerow",
+}
+
+RAW_BLOCK = re.compile(r"\{%-?\s*raw\s*-?%\}.*?\{%-?\s*endraw\s*-?%\}", re.S)
+COMMENT_BLOCK = re.compile(r"\{%-?\s*comment\s*-?%\}.*?\{%-?\s*endcomment\s*-?%\}", re.S)
+LIQUID_MARKUP = re.compile(r"\{\{(.*?)\}\}|\{%(.*?)%\}", re.S)
+STRING_LITERAL = re.compile(r"\"[^\"]*\"|'[^']*'")
+# Liquid identifiers may contain hyphens, e.g. `project-name`.
+IDENTIFIER = re.compile(r"[A-Za-z_][A-Za-z0-9_-]*(?:\.[A-Za-z0-9_-]+)*")
+FILTER = re.compile(r"\|\s*[A-Za-z_][A-Za-z0-9_]*")
+RHAI_SET = re.compile(r"variable::set\(\s*\"([^\"]+)\"")
+RHAI_GET = re.compile(r"variable::get\(\s*\"([^\"]+)\"")
+# Rhai expressions in `[conditional]` keys, strings removed first.
+CONDITION_IDENTIFIER = re.compile(r"[A-Za-z_][A-Za-z0-9_-]*")
+
+
+class Usage:
+    """Where each variable is used, for error messages."""
+
+    def __init__(self):
+        self.locations: Dict[str, Set[str]] = {}
+
+    def add(self, name: str, location: str) -> None:
+        self.locations.setdefault(name, set()).add(location)
+
+    def names(self) -> Set[str]:
+        return set(self.locations)
+
+
+def liquid_variables(source: str) -> Set[str]:
+    """Return the variables referenced by Liquid markup in `source`, minus ones it assigns itself."""
+    source = RAW_BLOCK.sub("", source)
+    source = COMMENT_BLOCK.sub("", source)
+
+    used = set()
+    local = set()
+    for match in LIQUID_MARKUP.finditer(source):
+        is_tag = match.group(2) is not None
+        markup = (match.group(2) if is_tag else match.group(1)).strip().strip("-").strip()
+        markup = STRING_LITERAL.sub("", markup)
+        markup = FILTER.sub("|", markup)
+
+        if is_tag:
+            words = markup.split()
+            if not words:
+                continue
+            tag = words[0]
+            if tag in ("assign", "capture") and len(words) > 1:
+                local.add(words[1])
+            elif tag == "for" and len(words) > 1:
+                local.add(words[1])
+                local.add("forloop")
+
+        for identifier in IDENTIFIER.findall(markup):
+            root = identifier.split(".")[0]
+            if root not in LIQUID_KEYWORDS and not root.isdigit():
+                used.add(root)
+
+    return used - local
+
+
+def condition_variables(condition: str) -> Set[str]:
+    """Return the variables in a `[conditional]` Rhai expression."""
+    condition = STRING_LITERAL.sub("", condition)
+    return {name for name in CONDITION_IDENTIFIER.findall(condition) if name not in ("true", "false")}
+
+
+def check(template_path: Path) -> bool:
+    """Check the template at `template_path`. Returns `True` if all variables are consistent."""
+    with open(template_path / "cargo-generate.toml", "rb") as f:
+        config = tomllib.load(f)
+
+    defined: Dict[str, str] = {}
+    used = Usage()
+
+    # Placeholders, including ones only asked for under a condition.
+    for name in config.get("placeholders", {}):
+        defined[name] = "cargo-generate.toml"
+    for condition, section in config.get("conditional", {}).items():
+        for name in section.get("placeholders", {}):
+            defined[name] = f"cargo-generate.toml ([conditional.'{condition}'])"
+        for name in condition_variables(condition):
+            used.add(name, f"cargo-generate.toml ([conditional.'{condition}'])")
+
+    # Rhai hooks set and read variables by name.
+    hooks = config.get("hooks", {})
+    for script in hooks.get("pre", []) + hooks.get("post", []):
+        source = (template_path / script).read_text(encoding="utf-8")
+        for name in RHAI_SET.findall(source):
+            defined.setdefault(name, script)
+        for name in RHAI_GET.findall(source):
+            used.add(name, script)
+
+    # Everything cargo-generate renders. `ignore` files are dropped, `exclude` files are copied as-is.
+    template = config.get("template", {})
+    skipped = {template_path / path for path in template.get("ignore", []) + template.get("exclude", [])}
+    files = 0
+    for path in sorted(template_path.rglob("*")):
+        if any(path == skip or skip in path.parents for skip in skipped):
+            continue
+        relative = path.relative_to(template_path).as_posix()
+        if ".git" in path.parts:
+            continue
+
+        # File and directory names are rendered too, e.g. `src/{{project-name}}`.
+        for name in liquid_variables(path.name):
+            used.add(name, relative)
+
+        if not path.is_file():
+            continue
+        try:
+            source = path.read_text(encoding="utf-8")
+        except UnicodeDecodeError:
+            continue
+        files += 1
+        for name in liquid_variables(source):
+            used.add(name, relative)
+
+    errors = 0
+    known = set(defined) | BUILTIN_VARIABLES
+
+    for name in sorted(used.names() - known):
+        locations = ", ".join(sorted(used.locations[name]))
+        hint = difflib.get_close_matches(name, known, n=1)
+        suggestion = f" Did you mean '{hint[0]}'?" if hint else ""
+        logger.error(f"✗ '{name}' is used but never defined (in {locations}).{suggestion}")
+        errors += 1
+
+    for name in sorted(set(defined) - used.names()):
+        logger.error(f"✗ '{name}' is defined in {defined[name]}, but never used")
+        errors += 1
+
+    if errors == 0:
+        logger.info(f"✓ Template variables are consistent ({len(defined)} defined, {files} files checked)")
+        return True
+    else:
+        logger.error(f"✗ Template variable check failed with {errors} error(s)")
+        return False
+
+
+def parse_args() -> argparse.Namespace:
+    """Parse command line arguments."""
+    repo_root = Path(__file__).parent.parent.parent
+    parser = argparse.ArgumentParser(description="Check that the template's Liquid variables are all defined and used")
+    parser.add_argument(
+        "--template",
+        type=Path,
+        action="append",
+        help="Template directory to check (default: every template with a cargo-generate.toml)",
+    )
+    args = parser.parse_args()
+    if not args.template:
+        args.template = sorted(path.parent for path in (repo_root / "templates").glob("*/cargo-generate.toml"))
+    return args
+
+
+def main() -> int:
+    """Main entry point."""
+    args = parse_args()
+
+    passed = True
+    for template_path in args.template:
+        logger.info(f"Checking template variables in {template_path}...")
+        passed &= check(template_path)
+
+    return 0 if passed else 1
+
+
+if __name__ == "__main__":
+    sys.exit(main())
@@ -0,0 +1,348 @@
+#!/usr/bin/env python3
+"""
+Pairwise (all-pairs) configuration matrix for cargo-generate templates.
+
+Reads the options from `cargo-generate.toml` and the `no_std_support` prompt in `pre-script.rhai`,
+then picks configurations that cover every pair of option values at least once. Conditional
+placeholders (e.g. `build_with_pgo`) are only set when their condition holds, so every
+configuration is one a user could really generate.
+
+Each configuration is generated, then checked with `cargo check`, `cargo test` and the CI gates
+(clippy, rustfmt and rustdoc) that its generated `rust.yml` runs.
+Option value pairs that only appear in failing configurations are reported as suspects.
+
+Run locally with:
+    python3 .github/tests/pairwise_matrix.py --list   # Print the matrix only
+    python3 .github/tests/pairwise_matrix.py          # Generate and test every configuration
+"""
+
+import argparse
+import itertools
+import logging
+import re
+import shutil
+import sys
+import tempfile
+from pathlib import Path
+from typing import Any, Dict, List, Optional, Set, Tuple
+
+# Import TOML parser (tomllib for Python 3.11+, tomli for older versions)
+if sys.version_info >= (3, 11):
+    import tomllib
+else:
+    try:
+        import tomli as tomllib
+    except ImportError:
+        print("Error: tomli package required for Python <3.11. Install with: pip install tomli", file=sys.stderr)
+        sys.exit(1)
+
+from test_template import TemplateTestConfig, generate_project, run_ci_gates, run_command
+
+# Configure logging
+logging.basicConfig(
+    level=logging.INFO,
+    format='%(levelname)s: %(message)s'
+)
+logger = logging.getLogger(__name__)
+
+# Placeholders the tests always set to the same value, as they don't change what's generated.
+FIXED_PLACEHOLDERS = {"gh_username", "gh_reponame", "project_description"}
+
+# Combinations `pre-script.rhai` rewrites, so they'd only repeat another configuration.
+CONSTRAINTS = [
+    # Bare metal targets need a `no_std` option, otherwise `bare_metal` is turned off.
+    (("bare_metal", "no_std_support"), lambda c: not c["bare_metal"] or c["no_std_support"] != "STD"),
+]
+
+RHAI_PROMPT = re.compile(r"(\w+)\s*=\s*variable::prompt\(\s*\"[^\"]*\",\s*\"[^\"]*\",\s*\[(.*?)\]\s*\)", re.S)
+STRING_LITERAL = re.compile(r"\"[^\"]*\"")
+IDENTIFIER = re.compile(r"\b(?!true\b|false\b)[A-Za-z_][A-Za-z0-9_]*\b")
+
+Config = Dict[str, Any]
+Pair = Tuple[str, Any, str, Any]
+
+
+class Option:
+    """A template option, and the condition under which it's asked for."""
+
+    def __init__(self, name: str, values: List[Any], condition: Optional[str] = None):
+        self.name = name
+        self.values = values
+        self.condition = condition
+
+    def is_active(self, config: Config) -> bool:
+        """Evaluate the Rhai condition from `cargo-generate.toml` against `config`."""
+        if self.condition is None:
+            return True
+        # Swap Rhai operators for Python ones, and variables for lookups. Strings are kept as-is.
+        parts = re.split(r"(\"[^\"]*\")", self.condition)
+        for i in range(0, len(parts), 2):
+            part = IDENTIFIER.sub(lambda m: f"c[{m.group(0)!r}]", parts[i])
+            part = part.replace("&&", " and ").replace("||", " or ")
+            parts[i] = re.sub(r"\btrue\b", "True", re.sub(r"\bfalse\b", "False", part))
+        return bool(eval("".join(parts), {}, {"c": config}))
+
+    def condition_variables(self) -> Set[str]:
+        if self.condition is None:
+            return set()
+        return set(IDENTIFIER.findall(STRING_LITERAL.sub("", self.condition)))
+
+
+def load_options(template_path: Path) -> List[Option]:
+    """Read the options a user is asked for, in the order they're asked."""
+    with open(template_path / "cargo-generate.toml", "rb") as f:
+        config = tomllib.load(f)
+
+    def values(placeholder: Dict[str, Any]) -> List[Any]:
+        if placeholder.get("type") == "bool":
+            return [True, False]
+        return list(placeholder["choices"])
+
+    options = []
+    for name, placeholder in config.get("placeholders", {}).items():
+        if name not in FIXED_PLACEHOLDERS:
+            options.append(Option(name, values(placeholder)))
+    for condition, section in config.get("conditional", {}).items():
+        for name, placeholder in section.get("placeholders", {}).items():
+            options.append(Option(name, values(placeholder), condition))
+
+    # Asked for by the pre hook instead, see `pre-script.rhai`.
+    for script in config.get("hooks", {}).get("pre", []):
+        source = (template_path / script).read_text(encoding="utf-8")
+        for name, choices in RHAI_PROMPT.findall(source):
+            options.append(Option(name, re.findall(r"\"([^\"]*)\"", choices)))
+
+    return options
+
+
+class PairwiseMatrix:
+    """Greedily picks configurations until every feasible pair of option values is covered."""
+
+    def __init__(self, options: List[Option]):
+        self.options = options
+        self.by_name = {option.name: option for option in options}
+
+        # Options whose values depend on each other. Everything else can be set freely.
+        constrained = set()
+        for option in options:
+            if option.condition is not None:
+                constrained.add(option.name)
+                constrained |= option.condition_variables()
+        for names, _ in CONSTRAINTS:
+            constrained |= set(names)
+        self.constrained = [option for option in options if option.name in constrained]
+        self.free = [option for option in options if option.name not in constrained]
+
+        # Every valid assignment of the constrained options; there are only a few hundred.
+        self.partials = []
+        choices = [option.values + ([None] if option.condition else []) for option in self.constrained]
+        for combination in itertools.product(*choices):
+            partial = dict(zip((option.name for option in self.constrained), combination))
+            if self._is_valid(partial):
+                self.partials.append(partial)
+
+        self.uncovered = self._feasible_pairs()
+
+    def _is_valid(self, config: Config) -> bool:
+        for option in self.constrained:
+            if option.condition is not None and option.is_active(config) != (config[option.name] is not None):
+                return False
+        return all(check(config) for _, check in CONSTRAINTS)
+
+    def _feasible_pairs(self) -> Set[Pair]:
+        pairs = set()
+        for first, second in itertools.combinations(self.options, 2):
+            for a, b in itertools.product(first.values, second.values):
+                if any(partial.get(first.name, a) == a and partial.get(second.name, b) == b for partial in self.partials):
+                    pairs.add((first.name, a, second.name, b))
+        return pairs
+
+    def _new_pairs(self, config: Config, name: str, value: Any) -> int:
+        count = 0
+        for other, other_value in config.items():
+            if (other, other_value, name, value) in self.uncovered or (name, value, other, other_value) in self.uncovered:
+                count += 1
+        return count
+
+    def _covered(self, config: Config) -> Set[Pair]:
+        names = [option.name for option in self.options]
+        covered = set()
+        for first, second in itertools.combinations(names, 2):
+            covered.add((first, config[first], second, config[second]))
+        return covered & self.uncovered
+
+    def generate(self) -> List[Config]:
+        """Return configurations covering every feasible pair, in the order they were picked."""
+        configs = []
+        while self.uncovered:
+            # Build around one uncovered pair, so every configuration covers at least that.
+            first, a, second, b = min(self.uncovered, key=str)
+            seed = {first: a, second: b}
+
+            best, best_covered = None, set()
+            for partial in self.partials:
+                if any(partial.get(name, value) != value for name, value in seed.items()):
+                    continue
+                config = dict(partial)
+                for option in self.free:
+                    if option.name in seed:
+                        config[option.name] = seed[option.name]
+                    else:
+                        config[option.name] = max(option.values, key=lambda value: self._new_pairs(config, option.name, value))
+                covered = self._covered(config)
+                if len(covered) > len(best_covered):
+                    best, best_covered = config, covered
+            self.uncovered -= best_covered
+            configs.append({option.name: best[option.name] for option in self.options})
+        return configs
+
+
+def to_test_config(index: int, config: Config) -> TemplateTestConfig:
+    """Convert a matrix entry into the arguments `test_template.py` takes."""
+    return TemplateTestConfig(argparse.Namespace(
+        project_name=f"pairwise_{index:02}",
+        mkdocs=config["mkdocs"],
+        vscode=config["vscode"],
+        xplat=config["xplat"],
+        big_endian=bool(config["big_endian"]),
+        bare_metal=config["bare_metal"],
+        wine=config["wine"],
+        bench=config["bench"],
+        miri=config["miri"],
+        fuzz=config["fuzz"],
+        build_c_libs=config["build_c_libs"],
+        build_csharp_libs=bool(config["build_csharp_libs"]),
+        build_wasm=config["build_wasm"],
+        build_python_libs=config["build_python_libs"],
+        build_with_pgo=bool(config["build_with_pgo"]),
+        project_kind=config["project_kind"],
+        xtask=config["xtask"],
+        tracing=config["tracing"],
+        publish_crate_on_tag=config["publish_crate_on_tag"],
+        license=config["license"],
+        no_std=config["no_std_support"],
+    ))
+
+
+def describe(config: Config) -> str:
+    """One line summary, listing enabled flags and chosen values."""
+    parts = []
+    for name, value in config.items():
+        if value is True:
+            parts.append(name)
+        elif isinstance(value, str):
+            parts.append(f"{name}={value}")
+    return ", ".join(parts)
+
+
+def run_config(index: int, config: Config, temp_dir: Path, target_dir: Path) -> Optional[str]:
+    """Generate and test one configuration. Returns the failing step, or `None` if it passed."""
+    test_config = to_test_config(index, config)
+    success, project_path = generate_project(test_config, temp_dir)
+    if not success or project_path is None:
+        return "cargo generate"
+
+    # Dependencies are shared between configurations, so they're only built once.
+    env = {"CARGO_TARGET_DIR": str(target_dir)}
+    for step in (["cargo", "check"], ["cargo", "test"]):
+        if not run_command(step, project_path / "src", env)[0]:
+            return " ".join(step)
+    if not run_ci_gates(project_path / "src", env):
+        return "CI gates"
+
+    shutil.rmtree(project_path, ignore_errors=True)
+    return None
+
+
+def report_suspects(configs: List[Config], failures: Dict[int, str]) -> None:
+    """Log the option value pairs that only appear in failing configurations."""
+    def pairs(config: Config) -> Set[Tuple[str, Any, str, Any]]:
+        return {(a, config[a], b, config[b]) for a, b in itertools.combinations(config, 2)}
+
+    failing = set().union(*(pairs(configs[i]) for i in failures))
+    passing = set().union(*(pairs(config) for i, config in enumerate(configs) if i not in failures))
+    suspects = sorted(failing - passing, key=str)
+    if not suspects:
+        logger.info("No option pair only appears in failing configurations")
+        return
+
+    logger.error("Option pairs only seen in failing configurations:")
+    for a, a_value, b, b_value in suspects:
+        logger.error(f"  {a}={a_value}, {b}={b_value}")
+
+
+def build_parser() -> argparse.ArgumentParser:
+    """Build the command line argument parser."""
+    repo_root = Path(__file__).parent.parent.parent
+    parser = argparse.ArgumentParser(description="Generate and test a pairwise matrix of template configurations")
+    parser.add_argument(
+        "--template",
+        type=Path,
+        default=repo_root / "templates" / "general",
+        help="Template directory (default: templates/general)"
+    )
+    parser.add_argument(
+        "--list",
+        action="store_true",
+        help="Only print the matrix, without generating anything"
+    )
+    parser.add_argument(
+        "--only",
+        type=int,
+        action="append",
+        help="Only test the configuration with this index (can be repeated)"
+    )
+    return parser
+
+
+def main() -> int:
+    """Main entry point."""
+    parser = build_parser()
+    args = parser.parse_args()
+
+    options = load_options(args.template)
+    matrix = PairwiseMatrix(options)
+    pair_count = len(matrix.uncovered)
+    configs = matrix.generate()
+    logger.info(f"{len(configs)} configurations cover all {pair_count} feasible option pairs ({len(options)} options)")
+
+    for index, config in enumerate(configs):
+        logger.info(f"  [{index:02}] {describe(config)}")
+    invalid = [index for index in args.only or [] if not 0 <= index < len(configs)]
+    if invalid:
+        parser.error(f"--only {invalid[0]} is out of range, the matrix has configurations 0 to {len(configs) - 1}")
+    if args.list:
+        return 0
+
+    indices = args.only if args.only else range(len(configs))
+    temp_dir = Path(tempfile.mkdtemp(prefix="template-pairwise-"))
+    logger.info(f"Using temporary directory: {temp_dir}")
+
+    failures: Dict[int, str] = {}
+    try:
+        for index in indices:
+            logger.info(f"Testing configuration [{index:02}]: {describe(configs[index])}")
+            failed_step = run_config(index, configs[index], temp_dir, temp_dir / "target")
+            if failed_step is None:
+                logger.info(f"✓ Configuration [{index:02}] passed")
+            else:
+                logger.error(f"✗ Configuration [{index:02}] failed at {failed_step}")
+                failures[index] = failed_step
+    finally:
+        logger.info(f"Cleaning up temporary directory: {temp_dir}")
+        shutil.rmtree(temp_dir, ignore_errors=True)
+
+    if not failures:
+        logger.info(f"✨ All {len(indices)} configurations passed!")
+        return 0
+
+    logger.error(f"❌ {len(failures)} of {len(indices)} configurations failed:")
+    for index, step in failures.items():
+        logger.error(f"  [{index:02}] {step}: {describe(configs[index])}")
+    if not args.only:
+        report_suspects(configs, failures)
+    return 1
+
+
+if __name__ == "__main__":
+    sys.exit(main())
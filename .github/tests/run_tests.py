@@ -5,7 +5,11 @@ Creates virtual environment and runs tests locally.
 
 This script handles:
 - Virtual environment creation and dependency installation
+- Template variable consistency check (check_template_variables.py)
+- Snapshot tests of generated projects (snapshot_tests.py)
 - Integration test execution with multiple configurations
+- Reloaded-III mod template tests (test_reloaded3.py)
+- Optional pairwise configuration matrix (pairwise_matrix.py, with --pairwise)
 - Cross-platform support (Windows, Linux, macOS, NixOS)
 """
 
@@ -163,10 +167,16 @@ def run_integration_test(
         print_info(f"  VSCode: {config['VSCode']}")
         print_info(f"  Cross-Platform: {config['XPlat']}")
         print_info(f"  Big-Endian: {config['BigEndian']}")
+        print_info(f"  Bare Metal: {config['BareMetal']}")
         print_info(f"  C Libraries: {config['BuildCLibs']}")
         print_info(f"  C# Bindings: {config['BuildCSharpLibs']}")
-        print_info(f"  CLI: {config['BuildCli']}")
+        print_info(f"  WebAssembly: {config['BuildWasm']}")
+        print_info(f"  Python Bindings: {config['BuildPythonLibs']}")
+        print_info(f"  Project Kind: {config['ProjectKind']}")
+        print_info(f"  xtask: {config['Xtask']}")
+        print_info(f"  Tracing: {config['Tracing']}")
         print_info(f"  Fuzz: {config['Fuzz']}")
+        print_info(f"  no_std: {config['NoStd']}")
         print()
         
         # Build command line arguments
@@ -178,13 +188,18 @@ def run_integration_test(
             f"--vscode={'true' if config['VSCode'] else 'false'}",
             f"--xplat={'true' if config['XPlat'] else 'false'}",
             f"--big-endian={'true' if config['BigEndian'] else 'false'}",
+            f"--bare-metal={'true' if config['BareMetal'] else 'false'}",
             f"--wine={'true' if config['Wine'] else 'false'}",
             f"--bench={'true' if config['Bench'] else 'false'}",
             f"--miri={'true' if config['Miri'] else 'false'}",
             f"--build-c-libs={'true' if config['BuildCLibs'] else 'false'}",
             f"--build-csharp-libs={'true' if config['BuildCSharpLibs'] else 'false'}",
+            f"--build-wasm={'true' if config['BuildWasm'] else 'false'}",
+            f"--build-python-libs={'true' if config['BuildPythonLibs'] else 'false'}",
             f"--build-with-pgo={'true' if config['BuildWithPgo'] else 'false'}",
-            f"--build-cli={'true' if config['BuildCli'] else 'false'}",
+            f"--project-kind={config['ProjectKind']}",
+            f"--xtask={'true' if config['Xtask'] else 'false'}",
+            f"--tracing={'true' if config['Tracing'] else 'false'}",
             f"--publish-crate-on-tag={'true' if config['PublishCrateOnTag'] else 'false'}",
             f"--license={config['License']}",
             f"--no-std={config['NoStd']}",
@@ -212,6 +227,101 @@ def run_integration_test(
         return False
 
 
+def run_reloaded3_test(
+    python_exe: Path,
+    script_dir: Path,
+    config_name: str,
+    config: Dict[str, Any],
+    verbose: bool = False
+) -> bool:
+    """Generate the `reloaded3` mod template with a configuration, and test it with the mock loader."""
+    print_section(f"Running Reloaded-III Mod Test: {config['DisplayName']}")
+    
+    print_info(f"Configuration: {config_name}")
+    print_info(f"  Project: {config['ProjectName']}")
+    print_info(f"  Cross-Platform: {config['XPlat']}")
+    print_info(f"  C# Bindings: {config['BuildCSharpLibs']}")
+    print()
+    
+    cmd = [
+        str(python_exe),
+        str(script_dir / "test_reloaded3.py"),
+        "--project-name", config['ProjectName'],
+        f"--xplat={'true' if config['XPlat'] else 'false'}",
+        f"--build-csharp-libs={'true' if config['BuildCSharpLibs'] else 'false'}",
+        f"--license={config['License']}"
+    ]
+    
+    start_time = time.time()
+    result = run_command(cmd, check=False, verbose=verbose)
+    duration = time.time() - start_time
+    
+    if result.returncode == 0:
+        print_success(f"Reloaded-III mod test '{config_name}' passed (took {duration:.1f}s)")
+        return True
+    else:
+        print_error(f"Reloaded-III mod test '{config_name}' failed")
+        if not verbose and result.stdout:
+            print(result.stdout)
+        if not verbose and result.stderr:
+            print(result.stderr)
+        return False
+
+
+def run_variable_check(python_exe: Path, script_dir: Path, verbose: bool = False) -> bool:
+    """Check every Liquid variable in the templates is defined, and every defined one is used."""
+    print_section("Checking Template Variables")
+    
+    check_script = script_dir / "check_template_variables.py"
+    result = run_command([str(python_exe), str(check_script)], check=False, verbose=verbose)
+    if result.returncode == 0:
+        print_success("Template variables are consistent")
+        return True
+    else:
+        print_error("Template variable check failed")
+        if not verbose and result.stdout:
+            print(result.stdout)
+        if not verbose and result.stderr:
+            print(result.stderr)
+        return False
+
+
+def run_snapshot_tests(python_exe: Path, script_dir: Path, verbose: bool = False) -> bool:
+    """Compare each configuration's generated files against the committed snapshots."""
+    print_section("Checking Snapshots")
+    
+    snapshot_script = script_dir / "snapshot_tests.py"
+    result = run_command([str(python_exe), str(snapshot_script)], check=False, verbose=verbose)
+    if result.returncode == 0:
+        print_success("Generated projects match their snapshots")
+        return True
+    else:
+        print_error("Generated projects differ from their snapshots")
+        if not verbose and result.stdout:
+            print(result.stdout)
+        if not verbose and result.stderr:
+            print(result.stderr)
+        return False
+
+
+def run_pairwise_matrix(python_exe: Path, script_dir: Path, verbose: bool = False) -> bool:
+    """Generate and test configurations covering every pair of option values."""
+    print_section("Running Pairwise Configuration Matrix")
+    
+    matrix_script = script_dir / "pairwise_matrix.py"
+    start_time = time.time()
+    # Always streamed, as it takes a while and reports each configuration as it goes.
+    result = run_command([str(python_exe), str(matrix_script)], check=False, verbose=True)
+    duration = time.time() - start_time
+    
+    if result.returncode == 0:
+        print_success(f"Pairwise matrix passed (took {duration:.1f}s)")
+        return True
+    else:
+        print_error("Pairwise matrix failed")
+        return False
+
+
 def get_test_configurations() -> Dict[str, Dict[str, Any]]:
     """Return dict of all test configurations."""
     return {
@@ -222,14 +332,19 @@ def get_test_configurations() -> Dict[str, Dict[str, Any]]:
             'VSCode': True,
             'XPlat': True,
             'BigEndian': False,
+            'BareMetal': False,
             'Wine': True,
             'Bench': True,
             'Miri': False,
             'Fuzz': False,
             'BuildCLibs': True,
             'BuildCSharpLibs': False,
+            'BuildWasm': False,
+            'BuildPythonLibs': False,
             'BuildWithPgo': True,
-            'BuildCli': False,
+            'ProjectKind': 'library',
+            'Xtask': True,
+            'Tracing': False,
             'PublishCrateOnTag': True,
             'License': 'GPL v3 (with Reloaded FAQ)',
             'NoStd': 'STD'
@@ -241,14 +356,19 @@ def get_test_configurations() -> Dict[str, Dict[str, Any]]:
             'VSCode': True,
             'XPlat': True,
             'BigEndian': True,
+            'BareMetal': True,
             'Wine': True,
             'Bench': True,
             'Miri': True,
             'Fuzz': True,
             'BuildCLibs': True,
             'BuildCSharpLibs': True,
+            'BuildWasm': True,
+            'BuildPythonLibs': True,
             'BuildWithPgo': True,
-            'BuildCli': True,
+            'ProjectKind': 'service',
+            'Xtask': True,
+            'Tracing': True,
             'PublishCrateOnTag': True,
             'License': 'MIT',
             'NoStd': 'STD'
@@ -260,14 +380,19 @@ def get_test_configurations() -> Dict[str, Dict[str, Any]]:
             'VSCode': False,
             'XPlat': False,
             'BigEndian': False,
+            'BareMetal': False,
             'Wine': False,
             'Bench': False,
             'Miri': False,
             'Fuzz': False,
             'BuildCLibs': False,
             'BuildCSharpLibs': False,
+            'BuildWasm': False,
+            'BuildPythonLibs': False,
             'BuildWithPgo': False,
-            'BuildCli': False,
+            'ProjectKind': 'library',
+            'Xtask': False,
+            'Tracing': False,
             'PublishCrateOnTag': False,
             'License': 'Apache 2.0',
             'NoStd': 'STD'
@@ -279,14 +404,19 @@ def get_test_configurations() -> Dict[str, Dict[str, Any]]:
             'VSCode': True,
             'XPlat': True,
             'BigEndian': False,
+            'BareMetal': False,
             'Wine': True,
             'Bench': True,
             'Miri': False,
             'Fuzz': False,
             'BuildCLibs': True,
             'BuildCSharpLibs': True,
+            'BuildWasm': False,
+            'BuildPythonLibs': True,
             'BuildWithPgo': True,
-            'BuildCli': False,
+            'ProjectKind': 'library',
+            'Xtask': True,
+            'Tracing': False,
             'PublishCrateOnTag': True,
             'License': 'GPL v3 (with Reloaded FAQ)',
             'NoStd': 'STD'
@@ -298,14 +428,19 @@ def get_test_configurations() -> Dict[str, Dict[str, Any]]:
             'VSCode': True,
             'XPlat': True,
             'BigEndian': False,
+            'BareMetal': False,
             'Wine': True,
             'Bench': True,
             'Miri': False,
             'Fuzz': False,
             'BuildCLibs': True,
             'BuildCSharpLibs': False,
+            'BuildWasm': False,
+            'BuildPythonLibs': False,
             'BuildWithPgo': True,
-            'BuildCli': False,
+            'ProjectKind': 'cli',
+            'Xtask': True,
+            'Tracing': True,
             'PublishCrateOnTag': True,
             'License': 'GPL v3 (with Reloaded FAQ)',
             'NoStd': 'STD'
@@ -317,17 +452,114 @@ def get_test_configurations() -> Dict[str, Dict[str, Any]]:
             'VSCode': True,
             'XPlat': True,
             'BigEndian': True,
+            'BareMetal': False,
             'Wine': True,
             'Bench': True,
             'Miri': False,
             'Fuzz': False,
             'BuildCLibs': True,
             'BuildCSharpLibs': False,
+            'BuildWasm': False,
+            'BuildPythonLibs': False,
             'BuildWithPgo': True,
-            'BuildCli': False,
+            'ProjectKind': 'library',
+            'Xtask': True,
+            'Tracing': False,
             'PublishCrateOnTag': True,
             'License': 'GPL v3 (with Reloaded FAQ)',
             'NoStd': 'STD'
+        },
+        'std_by_default': {
+            'DisplayName': 'no_std with std Feature by Default',
+            'ProjectName': 'test_std_by_default',
+            'Mkdocs': False,
+            'VSCode': False,
+            'XPlat': False,
+            'BigEndian': False,
+            'BareMetal': False,
+            'Wine': False,
+            'Bench': False,
+            'Miri': False,
+            'Fuzz': False,
+            'BuildCLibs': True,
+            'BuildCSharpLibs': False,
+            'BuildWasm': False,
+            'BuildPythonLibs': False,
+            'BuildWithPgo': False,
+            'ProjectKind': 'library',
+            'Xtask': False,
+            'Tracing': True,
+            'PublishCrateOnTag': False,
+            'License': 'MIT',
+            'NoStd': 'STD BY DEFAULT (VIA STD FEATURE)'
+        },
+        'no_std_by_default': {
+            'DisplayName': 'no_std by Default',
+            'ProjectName': 'test_no_std_by_default',
+            'Mkdocs': False,
+            'VSCode': False,
+            'XPlat': False,
+            'BigEndian': False,
+            'BareMetal': True,
+            'Wine': False,
+            'Bench': False,
+            'Miri': False,
+            'Fuzz': False,
+            'BuildCLibs': False,
+            'BuildCSharpLibs': False,
+            'BuildWasm': True,
+            'BuildPythonLibs': True,
+            'BuildWithPgo': False,
+            'ProjectKind': 'library',
+            'Xtask': False,
+            'Tracing': True,
+            'PublishCrateOnTag': False,
+            'License': 'MIT',
+            'NoStd': 'NO_STD BY DEFAULT'
+        },
+        'no_std_c_exports': {
+            'DisplayName': 'no_std by Default with C Exports',
+            'ProjectName': 'test_no_std_c_exports',
+            'Mkdocs': False,
+            'VSCode': False,
+            'XPlat': False,
+            'BigEndian': False,
+            'BareMetal': True,
+            'Wine': False,
+            'Bench': False,
+            'Miri': False,
+            'Fuzz': False,
+            'BuildCLibs': True,
+            'BuildCSharpLibs': False,
+            'BuildWasm': False,
+            'BuildPythonLibs': False,
+            'BuildWithPgo': False,
+            'ProjectKind': 'library',
+            'Xtask': False,
+            'Tracing': True,
+            'PublishCrateOnTag': False,
+            'License': 'MIT',
+            'NoStd': 'NO_STD BY DEFAULT'
+        }
+    }
+
+
+def get_reloaded3_configurations() -> Dict[str, Dict[str, Any]]:
+    """Return dict of `reloaded3` template test configurations."""
+    return {
+        'mod_defaults': {
+            'DisplayName': 'Mod With C# Bindings',
+            'ProjectName': 'test-mod',
+            'XPlat': True,
+            'BuildCSharpLibs': True,
+            'License': 'GPL v3 (with Reloaded FAQ)'
+        },
+        'mod_minimal': {
+            'DisplayName': 'Minimal Mod',
+            'ProjectName': 'test-mod-minimal',
+            'XPlat': False,
+            'BuildCSharpLibs': False,
+            'License': 'MIT'
         }
     }
 
@@ -365,6 +597,7 @@ def main() -> int:
 Examples:
   %(prog)s                           # Run all test configurations
   %(prog)s --verbose                 # Show detailed output
+  %(prog)s --pairwise                # Also test every pair of option values (slow)
         """
     )
     
@@ -374,6 +607,12 @@ Examples:
         help='Enable verbose output'
     )
     
+    parser.add_argument(
+        '--pairwise',
+        action='store_true',
+        help='Also run the pairwise configuration matrix (slow)'
+    )
+    
     args = parser.parse_args()
     
     # Print banner
@@ -391,7 +630,11 @@ Examples:
     
     # Track results
     results = {
-        'integration_tests': {}
+        'variable_check': False,
+        'snapshot_tests': False,
+        'integration_tests': {},
+        'reloaded3_tests': {},
+        'pairwise_matrix': None
     }
     
     start_time = time.time()
@@ -413,10 +656,14 @@ Examples:
     # Get Python executable from venv
     python_exe, _ = get_venv_executables(venv_dir)
     
+    # Check template variables first, it's fast and explains otherwise silent rendering bugs
+    results['variable_check'] = run_variable_check(python_exe, script_dir, args.verbose)
+    results['snapshot_tests'] = run_snapshot_tests(python_exe, script_dir, args.verbose)
+    
     # Run integration tests
     test_configs = get_test_configurations()
     
-    for config_name in ['defaults', 'all_on', 'all_off', 'c_bindings', 'pgo_enabled', 'big_endian']:
+    for config_name in ['defaults', 'all_on', 'all_off', 'c_bindings', 'pgo_enabled', 'big_endian', 'std_by_default', 'no_std_by_default', 'no_std_c_exports']:
         config_data = test_configs[config_name]
         results['integration_tests'][config_name] = run_integration_test(
             python_exe,
@@ -426,12 +673,36 @@ Examples:
             args.verbose
         )
     
+    mod_configs = get_reloaded3_configurations()
+    for config_name, config_data in mod_configs.items():
+        results['reloaded3_tests'][config_name] = run_reloaded3_test(
+            python_exe,
+            script_dir,
+            config_name,
+            config_data,
+            args.verbose
+        )
+    
+    if args.pairwise:
+        results['pairwise_matrix'] = run_pairwise_matrix(python_exe, script_dir, args.verbose)
+    
     # Print summary
     end_time = time.time()
     total_duration = end_time - start_time
     
     print_section("Test Summary")
     
+    # Variable check summary
+    print(f"\n{Colors.CYAN}Static Checks:{Colors.RESET}")
+    if results['variable_check']:
+        print_success("  Template variables: PASSED")
+    else:
+        print_error("  Template variables: FAILED")
+    if results['snapshot_tests']:
+        print_success("  Snapshots: PASSED")
+    else:
+        print_error("  Snapshots: FAILED (run snapshot_tests.py --update if the changes are intended)")
+    
     # Integration tests summary
     print(f"\n{Colors.CYAN}Integration Tests:{Colors.RESET}")
     passed_count = 0
@@ -454,13 +725,29 @@ Examples:
     if failed_count > 0:
         print_error(f"Failed: {failed_count} / {len(results['integration_tests'])}")
     
+    print(f"\n{Colors.CYAN}Reloaded-III Mod Tests:{Colors.RESET}")
+    for config_name, passed in results['reloaded3_tests'].items():
+        display_name = mod_configs[config_name]['DisplayName']
+        if passed:
+            print_success(f"  {display_name} ({config_name}): PASSED")
+        else:
+            print_error(f"  {display_name} ({config_name}): FAILED")
+    
+    if results['pairwise_matrix'] is not None:
+        print(f"\n{Colors.CYAN}Pairwise Matrix:{Colors.RESET}")
+        if results['pairwise_matrix']:
+            print_success("  All configurations: PASSED")
+        else:
+            print_error("  Some configurations: FAILED (see the log above for suspect option pairs)")
+    
     print()
     print_info(f"Total time: {total_duration:.1f}s")
     
     # Determine exit code
-    exit_code = 0
+    static_checks_passed = results['variable_check'] and results['snapshot_tests']
+    exit_code = 0 if static_checks_passed and results['pairwise_matrix'] is not False else 1
     
-    for result in results['integration_tests'].values():
+    for result in [*results['integration_tests'].values(), *results['reloaded3_tests'].values()]:
         if not result:
             exit_code = 1
             break
@@ -0,0 +1,190 @@
+#!/usr/bin/env python3
+"""
+Snapshot tests for cargo-generate templates.
+
+Generates each configuration from `run_tests.py`, then compares against the snapshots in
+`snapshots/<configuration>/`:
+- `tree.snap`: every generated file path
+- One `.snap` per key file (`Cargo.toml`s, `lib.rs`, `build.rs`, `rust.yml`), if generated
+
+Template changes then show up as a diff of the generated output in the PR.
+After an intended change, update the snapshots and commit them with it:
+    python3 .github/tests/snapshot_tests.py --update
+"""
+
+import argparse
+import difflib
+import logging
+import shutil
+import sys
+import tempfile
+from pathlib import Path
+from typing import Any, Dict, List
+
+from run_tests import get_test_configurations
+from test_template import TemplateTestConfig, generate_project
+
+# Configure logging
+logging.basicConfig(
+    level=logging.INFO,
+    format='%(levelname)s: %(message)s'
+)
+logger = logging.getLogger(__name__)
+
+SNAPSHOT_DIR = Path(__file__).parent / "snapshots"
+
+# Files whose full contents are snapshotted, relative to the project. `{crate}` is the project name.
+KEY_FILES = [
+    "src/Cargo.toml",
+    "src/{crate}/Cargo.toml",
+    "src/{crate}/src/lib.rs",
+    "src/{crate}/build.rs",
+    ".github/workflows/rust.yml",
+]
+
+
+def to_test_config(config: Dict[str, Any]) -> TemplateTestConfig:
+    """Convert a `run_tests.py` configuration into the arguments `test_template.py` takes."""
+    return TemplateTestConfig(argparse.Namespace(
+        project_name=config['ProjectName'],
+        mkdocs=config['Mkdocs'],
+        vscode=config['VSCode'],
+        xplat=config['XPlat'],
+        big_endian=config['BigEndian'],
+        bare_metal=config['BareMetal'],
+        wine=config['Wine'],
+        bench=config['Bench'],
+        miri=config['Miri'],
+        fuzz=config['Fuzz'],
+        build_c_libs=config['BuildCLibs'],
+        build_csharp_libs=config['BuildCSharpLibs'],
+        build_wasm=config['BuildWasm'],
+        build_python_libs=config['BuildPythonLibs'],
+        build_with_pgo=config['BuildWithPgo'],
+        project_kind=config['ProjectKind'],
+        xtask=config['Xtask'],
+        tracing=config['Tracing'],
+        publish_crate_on_tag=config['PublishCrateOnTag'],
+        license=config['License'],
+        no_std=config['NoStd'],
+    ))
+
+
+def snapshot_name(path: str) -> str:
+    """File name of the snapshot for `path`, e.g. `src__lib.rs.snap` for `src/lib.rs`."""
+    return path.replace("/", "__") + ".snap"
+
+
+def render_snapshots(project_path: Path, project_name: str) -> Dict[str, str]:
+    """Return the snapshots of a generated project, by snapshot file name."""
+    files = sorted(
+        path.relative_to(project_path).as_posix()
+        for path in project_path.rglob("*")
+        if path.is_file() and ".git" not in path.relative_to(project_path).parts
+    )
+    snapshots = {"tree.snap": "\n".join(files) + "\n"}
+
+    for key_file in KEY_FILES:
+        path = key_file.format(crate=project_name)
+        full_path = project_path / path
+        if full_path.exists():
+            # Line endings depend on the checkout, e.g. `core.autocrlf` on Windows.
+            content = full_path.read_text(encoding="utf-8").replace("\r\n", "\n")
+            snapshots[snapshot_name(path)] = content
+    return snapshots
+
+
+def compare(config_name: str, actual: Dict[str, str]) -> int:
+    """Compare against the stored snapshots, logging a diff for each mismatch. Returns the error count."""
+    snapshot_dir = SNAPSHOT_DIR / config_name
+    expected = {}
+    if snapshot_dir.exists():
+        for path in snapshot_dir.glob("*.snap"):
+            expected[path.name] = path.read_text(encoding="utf-8").replace("\r\n", "\n")
+
+    errors = 0
+    for name in sorted(set(expected) | set(actual)):
+        if name not in actual:
+            logger.error(f"✗ {config_name}/{name} is no longer generated")
+            errors += 1
+        elif name not in expected:
+            logger.error(f"✗ {config_name}/{name} has no snapshot")
+            errors += 1
+        elif expected[name] != actual[name]:
+            diff = difflib.unified_diff(
+                expected[name].splitlines(keepends=True),
+                actual[name].splitlines(keepends=True),
+                fromfile=f"snapshots/{config_name}/{name}",
+                tofile="generated",
+            )
+            logger.error(f"✗ {config_name}/{name} differs from its snapshot:\n{''.join(diff)}")
+            errors += 1
+    return errors
+
+
+def update(config_name: str, actual: Dict[str, str]) -> None:
+    """Replace the stored snapshots with `actual`."""
+    snapshot_dir = SNAPSHOT_DIR / config_name
+    shutil.rmtree(snapshot_dir, ignore_errors=True)
+    snapshot_dir.mkdir(parents=True)
+    for name, content in actual.items():
+        (snapshot_dir / name).write_text(content, encoding="utf-8", newline="\n")
+    logger.info(f"✓ Updated {len(actual)} snapshots for {config_name}")
+
+
+def parse_args() -> argparse.Namespace:
+    """Parse command line arguments."""
+    parser = argparse.ArgumentParser(description="Compare generated projects against their snapshots")
+    parser.add_argument(
+        "--update",
+        action="store_true",
+        help="Write the generated output as the new snapshots, instead of comparing"
+    )
+    parser.add_argument(
+        "configs",
+        nargs="*",
+        help="Configurations from run_tests.py to check (default: all)"
+    )
+    return parser.parse_args()
+
+
+def main() -> int:
+    """Main entry point."""
+    args = parse_args()
+    configs = get_test_configurations()
+    names: List[str] = args.configs or list(configs)
+
+    temp_dir = Path(tempfile.mkdtemp(prefix="template-snapshots-"))
+    errors = 0
+    try:
+        for name in names:
+            logger.info(f"Generating {name}...")
+            config = configs[name]
+            destination = temp_dir / name
+            destination.mkdir()
+            success, project_path = generate_project(to_test_config(config), destination)
+            if not success or project_path is None:
+                errors += 1
+                continue
+
+            actual = render_snapshots(project_path, config['ProjectName'])
+            if args.update:
+                update(name, actual)
+            else:
+                config_errors = compare(name, actual)
+                if config_errors == 0:
+                    logger.info(f"✓ {name} matches its {len(actual)} snapshots")
+                errors += config_errors
+    finally:
+        shutil.rmtree(temp_dir, ignore_errors=True)
+
+    if errors == 0:
+        return 0
+
+    logger.error(f"❌ {errors} snapshot(s) differ. If the changes are intended, run:")
+    logger.error("    python3 .github/tests/snapshot_tests.py --update")
+    return 1
+
+
+if __name__ == "__main__":
+    sys.exit(main())
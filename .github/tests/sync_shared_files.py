@@ -0,0 +1,114 @@
+#!/usr/bin/env python3
+"""
+Copies the files the `reloaded3` template shares with `templates/general`.
+
+cargo-generate only copies a template's own directory, and skips symbolic links, so a template can't
+use files from another one. The shared files are therefore edited in `templates/general` only, and this
+script copies them into `templates/reloaded3`. Where the mod needs something different, the file uses
+Liquid: `reloaded3_mod` is only true in the mod, and the mod's `pre-script.rhai` sets the other
+variables the shared files check.
+
+`--check` fails if a copy is out of date, and is run by `test_reloaded3.py`.
+
+Run locally with:
+    python3 .github/tests/sync_shared_files.py           # Update the copies
+    python3 .github/tests/sync_shared_files.py --check   # Only check them
+"""
+
+import argparse
+import logging
+import shutil
+import sys
+from pathlib import Path
+from typing import List
+
+# Configure logging
+logging.basicConfig(
+    level=logging.INFO,
+    format='%(levelname)s: %(message)s'
+)
+logger = logging.getLogger(__name__)
+
+REPO_ROOT = Path(__file__).parent.parent.parent
+SOURCE = REPO_ROOT / "templates" / "general"
+DESTINATION = REPO_ROOT / "templates" / "reloaded3"
+
+# Relative to both templates.
+SHARED_FILES = [
+    ".gitignore",
+    "final-msg.rhai",
+    "LICENSE-APACHE",
+    "LICENSE-GPL3",
+    "LICENSE-GPL3-R",
+    "LICENSE-LGPL3",
+    "LICENSE-MIT",
+    ".github/ISSUE_TEMPLATE/bug_report.yml",
+    ".github/ISSUE_TEMPLATE/config.yml",
+    ".github/ISSUE_TEMPLATE/feature_request.yml",
+    ".github/cbindgen_c.toml",
+    ".github/dependabot.yml",
+    ".github/pull_request_template.md",
+    "src/bindings/csharp/.gitignore",
+    "src/{{project-name}}/build/bindings.rs",
+    "src/{{project-name}}/src/exports/error.rs",
+    "src/{{project-name}}/src/exports/version.rs",
+    "src/{{project-name}}/tests/bindings.rs",
+]
+
+
+def outdated_files() -> List[str]:
+    """Return the shared files whose copy in `reloaded3` differs from `general`."""
+    outdated = []
+    for file in SHARED_FILES:
+        copy = DESTINATION / file
+        if not copy.is_file() or copy.read_bytes() != (SOURCE / file).read_bytes():
+            outdated.append(file)
+    return outdated
+
+
+def check() -> bool:
+    """Check every copy matches `general`. Returns `True` if they're all up to date."""
+    outdated = outdated_files()
+    if not outdated:
+        logger.info(f"✓ All {len(SHARED_FILES)} shared files match templates/general")
+        return True
+
+    for file in outdated:
+        logger.error(f"✗ templates/reloaded3/{file} differs from templates/general")
+    logger.error("Edit the files in templates/general, then run sync_shared_files.py to copy them")
+    return False
+
+
+def sync() -> None:
+    """Copy the outdated shared files from `general` to `reloaded3`."""
+    for file in outdated_files():
+        copy = DESTINATION / file
+        copy.parent.mkdir(parents=True, exist_ok=True)
+        shutil.copyfile(SOURCE / file, copy)
+        logger.info(f"Copied {file}")
+    logger.info(f"✓ All {len(SHARED_FILES)} shared files match templates/general")
+
+
+def parse_args() -> argparse.Namespace:
+    """Parse command line arguments."""
+    parser = argparse.ArgumentParser(description="Copy the files the reloaded3 template shares with the general one")
+    parser.add_argument(
+        "--check",
+        action="store_true",
+        help="Only check the copies are up to date, without changing them"
+    )
+    return parser.parse_args()
+
+
+def main() -> int:
+    """Main entry point."""
+    args = parse_args()
+    if args.check:
+        return 0 if check() else 1
+
+    sync()
+    return 0
+
+
+if __name__ == "__main__":
+    sys.exit(main())
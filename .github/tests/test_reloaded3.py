@@ -0,0 +1,210 @@
+#!/usr/bin/env python3
+"""
+Template testing script for the `reloaded3` mod template.
+
+This script generates a mod from `templates/reloaded3` and validates:
+- File structure (conditional includes/excludes)
+- Jinja2 template rendering completion
+- The committed bindings match the exports (`tests/bindings.rs`)
+- The files shared with `templates/general` are up to date (`sync_shared_files.py`)
+- The generated CI's gates (tests, clippy, rustfmt, rustdoc)
+- The mock loader loads, starts and unloads the mod
+"""
+
+import argparse
+import logging
+import os
+import shutil
+import sys
+import tempfile
+from pathlib import Path
+from typing import Optional, Tuple
+
+import sync_shared_files
+from test_template import cargo_generate, check_jinja2_remnants, run_ci_gates, run_command
+
+logger = logging.getLogger(__name__)
+
+MOD_NAME = "Test Mod"
+
+
+class ModValidator:
+    """Validates a mod generated from the `reloaded3` template."""
+
+    def __init__(self, project_path: Path, args: argparse.Namespace):
+        self.project_path = project_path
+        self.src_dir = project_path / "src"
+        self.args = args
+
+    def validate_file_structure(self) -> bool:
+        """Check the expected files exist, and the optional ones only when enabled."""
+        logger.info("Validating file structure...")
+
+        name = self.args.project_name
+        required = [
+            "README.MD",
+            "LICENSE",
+            ".github/workflows/rust.yml",
+            ".github/cbindgen_c.toml",
+            "src/Cargo.toml",
+            f"src/{name}/Cargo.toml",
+            f"src/{name}/package.toml",
+            f"src/{name}/build.rs",
+            f"src/{name}/src/lib.rs",
+            f"src/{name}/src/exports/entry.rs",
+            f"src/{name}/tests/mock_loader.rs",
+            f"src/{name}/tests/bindings.rs",
+            "src/mock-loader/Cargo.toml",
+            "src/mock-loader/src/lib.rs",
+            "src/mock-loader/src/main.rs",
+            f"src/bindings/c/{name}.h",
+        ]
+        csharp = ["src/bindings/csharp/NativeMethods.g.cs", "src/bindings/csharp/csharp.csproj"]
+
+        errors = 0
+        for file in required + (csharp if self.args.build_csharp_libs else []):
+            if not (self.project_path / file).exists():
+                logger.error(f"✗ Missing {file}")
+                errors += 1
+        if not self.args.build_csharp_libs and (self.src_dir / "bindings" / "csharp").exists():
+            logger.error("✗ src/bindings/csharp should not exist without build_csharp_libs")
+            errors += 1
+
+        # pre-script.rhai renames the chosen license to LICENSE, and removes the rest.
+        leftover = sorted(p.name for p in self.project_path.glob("LICENSE-*"))
+        if leftover:
+            logger.error(f"✗ Unused licenses were not removed: {', '.join(leftover)}")
+            errors += 1
+
+        if errors == 0:
+            logger.info("✓ File structure validation passed")
+            return True
+        logger.error(f"✗ File structure validation failed with {errors} error(s)")
+        return False
+
+    def check_jinja2_remnants(self) -> bool:
+        """Check for unconverted Jinja2 template syntax in the generated files."""
+        return check_jinja2_remnants(self.project_path, ['*.yml', '*.toml', '*.rs', '*.md', '*.MD', '*.h', '*.cs', '*.csproj'])
+
+    def validate_bindings(self) -> bool:
+        """Check the committed bindings are up to date, without regenerating them first."""
+        logger.info("Validating committed bindings...")
+        passed, _ = run_command(["cargo", "test", "-p", self.args.project_name, "--test", "bindings"], self.src_dir)
+        return passed
+
+    def validate_shared_files(self) -> bool:
+        """Check the files shared with the general template match it, so fixes reach both templates."""
+        logger.info("Validating shared files...")
+        return sync_shared_files.check()
+
+    def validate_ci_gates(self) -> bool:
+        """Run the same checks as the generated `rust.yml`, so mods pass CI on their first PR."""
+        return run_ci_gates(self.src_dir)
+
+    def validate_mock_loader(self) -> bool:
+        """Build the mod, then load it with the `mock-loader` binary as the mod's README shows."""
+        logger.info("Validating mock loader...")
+
+        passed, _ = run_command(["cargo", "build"], self.src_dir)
+        if not passed:
+            return False
+
+        package = f"{self.args.project_name}/package.toml"
+        # Honour CARGO_TARGET_DIR, as the default `--library-dir` assumes `target/debug`.
+        library_dir = Path(os.environ.get("CARGO_TARGET_DIR", self.src_dir / "target")) / "debug"
+        cmd = ["cargo", "run", "-p", "mock-loader", "--", package, "--library-dir", str(library_dir)]
+        passed, result = run_command(cmd, self.src_dir)
+        if not passed:
+            return False
+
+        expected = [f"[Info] {self.args.project_name} started", f"[Info] {self.args.project_name} unloaded"]
+        missing = [line for line in expected if line not in result.stdout.splitlines()]
+        if missing:
+            logger.error(f"✗ mock-loader output is missing {missing}:")
+            logger.error(result.stdout)
+            return False
+        logger.info("✓ Mock loader validation passed")
+        return True
+
+
+def generate_project(args: argparse.Namespace, temp_dir: Path) -> Tuple[bool, Optional[Path]]:
+    """Generate a mod using cargo-generate."""
+    logger.info("Generating mod with cargo-generate...")
+
+    defines = [
+        "gh_username=test-user",
+        f"mod_name={MOD_NAME}",
+        "project_description=Test mod for template validation",
+        f"license={args.license}",
+        f"xplat={str(args.xplat).lower()}",
+        f"build_csharp_libs={str(args.build_csharp_libs).lower()}",
+    ]
+    return cargo_generate("reloaded3", args.project_name, temp_dir, defines)
+
+
+def parse_args() -> argparse.Namespace:
+    """Parse command-line arguments."""
+    parser = argparse.ArgumentParser(
+        description="Test cargo-generate Reloaded-III mod template generation and validation"
+    )
+    parser.add_argument(
+        "--project-name",
+        required=True,
+        help="Project name (must be valid Rust crate name)"
+    )
+    parser.add_argument(
+        "--xplat",
+        type=lambda x: x.lower() == "true",
+        default=True,
+        help="Build and test on all platforms in CI (default: true)"
+    )
+    parser.add_argument(
+        "--build-csharp-libs",
+        type=lambda x: x.lower() == "true",
+        default=False,
+        help="Include C# bindings (default: false)"
+    )
+    parser.add_argument(
+        "--license",
+        default="GPL v3 (with Reloaded FAQ)",
+        help="License to generate the mod with (default: GPL v3 (with Reloaded FAQ))"
+    )
+    return parser.parse_args()
+
+
+def main() -> int:
+    """Main entry point."""
+    args = parse_args()
+
+    temp_dir = Path(tempfile.mkdtemp(prefix="reloaded3-test-"))
+    logger.info(f"Using temporary directory: {temp_dir}")
+
+    try:
+        success, project_path = generate_project(args, temp_dir)
+        if not success or project_path is None:
+            return 1
+
+        validator = ModValidator(project_path, args)
+
+        all_passed = True
+        all_passed &= validator.validate_file_structure()
+        all_passed &= validator.check_jinja2_remnants()
+        all_passed &= validator.validate_bindings()
+        all_passed &= validator.validate_shared_files()
+        all_passed &= validator.validate_ci_gates()
+        all_passed &= validator.validate_mock_loader()
+
+        if all_passed:
+            logger.info("✨ All validations passed!")
+            return 0
+        else:
+            logger.error("❌ Some validations failed")
+            return 1
+
+    finally:
+        logger.info(f"Cleaning up temporary directory: {temp_dir}")
+        shutil.rmtree(temp_dir, ignore_errors=True)
+
+
+if __name__ == "__main__":
+    sys.exit(main())
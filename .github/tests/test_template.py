@@ -7,12 +7,15 @@ This script generates Rust projects from templates and validates:
 - File formats (JSON, TOML, YAML)
 - Jinja2 template rendering completion
 - Build validation (cargo check, build, test)
+- The generated CI's gates (all-features tests, clippy, rustfmt, rustdoc)
+- C ABI validation (generated headers against Rust layouts)
 - MkDocs documentation builds
 """
 
 import argparse
 import json
 import logging
+import os
 import re
 import shutil
 import subprocess
@@ -45,6 +48,162 @@ logging.basicConfig(
 )
 logger = logging.getLogger(__name__)
 
+# Target without `std`, used to check that the `no_std` feature tiers don't use it.
+NO_STD_TARGET = "thumbv7em-none-eabi"
+
+# Targets built by the `bare_metal` option. Tests can't run on them, so they're only built.
+BARE_METAL_TARGETS = ["thumbv7em-none-eabihf", "riscv32imac-unknown-none-elf"]
+
+# Targets for `build_wasm`: tests run on WASI under wasmtime, the npm package is for the browser.
+WASI_TARGET = "wasm32-wasip1"
+WASM_TARGET = "wasm32-unknown-unknown"
+
+# File name Python imports an extension module from, see "Manual builds" in the PyO3 guide.
+PYTHON_MODULE_SUFFIX = ".pyd" if sys.platform == "win32" else ".abi3.so"
+
+# .NET RIDs for the `rust.yml` matrix targets, see https://learn.microsoft.com/en-us/dotnet/core/rid-catalog.
+# Targets that aren't here, like big-endian PowerPC, have no RID and aren't packaged for .NET.
+TARGET_RIDS = {
+    "x86_64-unknown-linux-gnu": "linux-x64",
+    "i686-unknown-linux-gnu": "linux-x86",
+    "aarch64-unknown-linux-gnu": "linux-arm64",
+    "armv7-unknown-linux-gnueabihf": "linux-arm",
+    "x86_64-unknown-linux-musl": "linux-musl-x64",
+    "aarch64-unknown-linux-musl": "linux-musl-arm64",
+    "x86_64-unknown-freebsd": "freebsd-x64",
+    "x86_64-pc-windows-msvc": "win-x64",
+    "i686-pc-windows-msvc": "win-x86",
+    "x86_64-apple-darwin": "osx-x64",
+    "aarch64-apple-darwin": "osx-arm64",
+}
+
+# The checks in the generated `rust.yml`, shared by every template.
+# Mirrors the "Run Tests", "Run linter", "Run formatter check" and "Check documentation is valid" steps.
+CI_GATES = [
+    (["cargo", "test", "--workspace", "--all-features"], {}),
+    (["cargo", "clippy", "--workspace", "--all-features", "--", "-D", "warnings"], {}),
+    (["cargo", "fmt", "--all", "--check"], {}),
+    (["cargo", "doc", "--workspace", "--all-features", "--document-private-items"], {"RUSTDOCFLAGS": "-D warnings"}),
+]
+
+
+def run_command(cmd: list, cwd: Path, env: Optional[dict] = None) -> Tuple[bool, subprocess.CompletedProcess]:
+    """Run a command, logging its output if it fails."""
+    command = " ".join(cmd)
+    logger.info(f"Running {command}...")
+    result = subprocess.run(
+        cmd,
+        cwd=cwd,
+        env=dict(os.environ, **(env or {})),
+        capture_output=True,
+        text=True,
+        encoding='utf-8',
+        errors='replace'
+    )
+    if result.returncode != 0:
+        logger.error(f"✗ {command} failed")
+        logger.error(result.stdout)
+        logger.error(result.stderr)
+        return False, result
+    logger.info(f"✓ {command} passed")
+    return True, result
+
+
+def run_ci_gates(src_dir: Path, env: Optional[dict] = None) -> bool:
+    """Run `CI_GATES` in the generated workspace, so projects pass CI on their first PR."""
+    logger.info("Validating CI gates...")
+    
+    errors = sum(not run_command(cmd, src_dir, {**(env or {}), **gate_env})[0] for cmd, gate_env in CI_GATES)
+    if errors == 0:
+        logger.info("✓ CI gate validation passed")
+        return True
+    else:
+        logger.error(f"✗ CI gate validation failed with {errors} error(s)")
+        return False
+
+
+def check_jinja2_remnants(project_path: Path, extensions: list) -> bool:
+    """Check for unconverted Jinja2 template syntax.
+    
+    This detects Jinja2 template remnants ({{ }} and {% %}) in generated files
+    while excluding GitHub Actions expressions (${{ }}) to avoid false positives.
+    """
+    logger.info("Checking for Jinja2 template remnants...")
+    
+    patterns = [r'\{%', r'\{\{']
+    
+    remnants = []
+    for ext in extensions:
+        for file_path in project_path.rglob(ext):
+            # Skip vendor directories
+            if "vendor" in file_path.parts or ".git" in file_path.parts:
+                continue
+            
+            try:
+                content = file_path.read_text()
+                for i, line in enumerate(content.splitlines(), 1):
+                    # Skip comments (simple heuristic)
+                    if line.strip().startswith('#') or line.strip().startswith('//'):
+                        continue
+                    
+                    # Skip lines with GitHub Actions expressions
+                    if '${{' in line:
+                        continue
+                    
+                    for pattern in patterns:
+                        if re.search(pattern, line):
+                            remnants.append(f"{file_path.relative_to(project_path)}:{i}: {line.strip()}")
+            except Exception as e:
+                logger.warning(f"Could not read {file_path}: {e}")
+    
+    if remnants:
+        logger.error("✗ Found Jinja2 template remnants:")
+        for remnant in remnants:
+            logger.error(f"  {remnant}")
+        return False
+    else:
+        logger.info("✓ No Jinja2 remnants found")
+        return True
+
+
+def cargo_generate(template: str, project_name: str, temp_dir: Path, defines: list) -> Tuple[bool, Optional[Path]]:
+    """Generate `project_name` from `templates/<template>` with cargo-generate, defining each `key=value` in `defines`."""
+    # Get repository root (two levels up from .github/tests/)
+    script_dir = Path(__file__).parent
+    repo_root = script_dir.parent.parent
+    template_path = repo_root / "templates" / template
+    
+    if not template_path.exists():
+        logger.error(f"Template path not found: {template_path}")
+        return False, None
+    
+    # Use --name for project name and --destination for output directory
+    cmd = [
+        "cargo", "generate",  # Two separate words, not hyphenated
+        "--path", str(template_path),
+        "--name", project_name,  # Project name (NOT a path)
+        "--destination", str(temp_dir),  # Where to generate
+    ]
+    for define in defines:
+        cmd.extend(["--define", define])
+    
+    logger.debug(f"Running: {' '.join(cmd)}")
+    
+    # Run cargo-generate
+    result = subprocess.run(cmd, capture_output=True, text=True, encoding='utf-8', errors='replace')
+    if result.returncode != 0:
+        logger.error("✗ cargo-generate failed")
+        logger.error(result.stderr)
+        return False, None
+    
+    generated_path = temp_dir / project_name
+    if not generated_path.exists():
+        logger.error(f"✗ Generated project not found at {generated_path}")
+        return False, None
+    
+    logger.info(f"✓ Project generated successfully at {generated_path}")
+    return True, generated_path
+
 
 class TemplateTestConfig:
     """Configuration for template generation and testing."""
@@ -55,14 +214,21 @@ class TemplateTestConfig:
         self.vscode = args.vscode
         self.xplat = args.xplat
         self.big_endian = args.big_endian
+        self.bare_metal = args.bare_metal
         self.wine = args.wine
         self.bench = args.bench
         self.miri = args.miri
         self.fuzz = args.fuzz
         self.build_c_libs = args.build_c_libs
         self.build_csharp_libs = args.build_csharp_libs
+        self.build_wasm = args.build_wasm
+        self.build_python_libs = args.build_python_libs
         self.build_with_pgo = args.build_with_pgo
-        self.build_cli = args.build_cli
+        self.project_kind = args.project_kind
+        self.build_cli = args.project_kind != "library"
+        self.service = args.project_kind == "service"
+        self.xtask = args.xtask
+        self.tracing = args.tracing
         self.publish_crate_on_tag = args.publish_crate_on_tag
         self.license = args.license
         self.no_std = args.no_std
@@ -102,10 +268,33 @@ class TemplateValidator:
         # C library validation
         if self.config.build_c_libs:
             errors += self._check_exists(f"src/{self.config.project_name}/src/exports.rs", "C exports file")
+            errors += self._check_exists(f"src/{self.config.project_name}/src/exports/error.rs", "C exports error module")
+            errors += self._check_exists(f"src/{self.config.project_name}/src/exports/ffi.rs", "C exports string helpers")
+            errors += self._check_exists(f"src/{self.config.project_name}/tests/c_abi.rs", "C ABI smoke test")
+            errors += self._check_exists(f"src/{self.config.project_name}/tests/bindings.rs", "bindings drift test")
+            errors += self._check_exists(f"src/{self.config.project_name}/build/bindings.rs", "bindings generator")
+            errors += self._check_exists(f"src/bindings/c/{self.config.project_name}.h", "committed C header")
+            errors += self._check_exists(f"src/bindings/cpp/{self.config.project_name}.hpp", "committed C++ header")
+            errors += self._check_exists("src/Cross.toml", "cross configuration")
             errors += self._check_exists(".github/cbindgen_c.toml", "cbindgen C config")
             errors += self._check_exists(".github/cbindgen_cpp.toml", "cbindgen C++ config")
+            if self.config.no_std == "STD":
+                errors += self._check_not_exists(f"src/{self.config.project_name}/src/exports/hooks.rs", "no_std runtime hooks")
+                errors += self._check_not_exists(f"src/{self.config.project_name}/src/exports/runtime.rs", "no_std runtime")
+                errors += self._check_not_exists(f"src/{self.config.project_name}/tests/c_abi/smoke_no_std.c", "no_std smoke test")
+            else:
+                errors += self._check_exists(f"src/{self.config.project_name}/src/exports/hooks.rs", "no_std runtime hooks")
+                errors += self._check_exists(f"src/{self.config.project_name}/src/exports/runtime.rs", "no_std runtime")
+                errors += self._check_exists(f"src/{self.config.project_name}/tests/c_abi/smoke_no_std.c", "no_std smoke test")
         else:
             errors += self._check_not_exists(f"src/{self.config.project_name}/src/exports.rs", "C exports file")
+            errors += self._check_not_exists(f"src/{self.config.project_name}/src/exports", "C exports directory")
+            errors += self._check_not_exists(f"src/{self.config.project_name}/tests/c_abi.rs", "C ABI smoke test")
+            errors += self._check_not_exists(f"src/{self.config.project_name}/tests/bindings.rs", "bindings drift test")
+            errors += self._check_not_exists(f"src/{self.config.project_name}/build", "bindings generator")
+            errors += self._check_not_exists("src/bindings/c", "committed C header")
+            errors += self._check_not_exists("src/bindings/cpp", "committed C++ header")
+            errors += self._check_not_exists("src/Cross.toml", "cross configuration")
             errors += self._check_not_exists(".github/cbindgen_c.toml", "cbindgen C config")
             errors += self._check_not_exists(".github/cbindgen_cpp.toml", "cbindgen C++ config")
             errors += self._check_not_exists("src/bindings/csharp", "C# bindings directory")
@@ -114,6 +303,12 @@ class TemplateValidator:
         if self.config.build_csharp_libs and self.config.build_c_libs:
             errors += self._check_exists("src/bindings/csharp/csharp.csproj", "C# project file")
             errors += self._check_exists("src/bindings/csharp/NativeMethods.cs", "C# native methods")
+            errors += self._check_exists("src/bindings/csharp/CounterHandle.cs", "C# counter SafeHandle")
+            errors += self._check_exists("src/bindings/csharp/Library.cs", "C# safe wrapper")
+            errors += self._check_exists("src/bindings/csharp/Counter.cs", "C# safe counter")
+            errors += self._check_exists("src/bindings/csharp/tests/LibraryTests.cs", "C# safe wrapper tests")
+            errors += self._check_exists("src/bindings/csharp/tests/NativeMethodsTests.cs", "C# library resolver tests")
+            errors += self._check_exists("src/bindings/csharp/tests/tests.csproj", "C# test project")
         elif self.config.build_c_libs:
             errors += self._check_not_exists("src/bindings/csharp", "C# bindings directory")
         
@@ -139,13 +334,77 @@ class TemplateValidator:
         else:
             errors += self._check_not_exists("src/fuzz", "Fuzz directory")
         
+        # Bare metal validation, dropped by `pre-script.rhai` without a `no_std` option
+        if self.config.bare_metal and self.config.no_std != "STD":
+            errors += self._check_exists("src/bare-metal-smoke/Cargo.toml", "Bare metal smoke test Cargo.toml")
+            errors += self._check_exists("src/bare-metal-smoke/src/main.rs", "Bare metal smoke test")
+        else:
+            errors += self._check_not_exists("src/bare-metal-smoke", "Bare metal smoke test directory")
+        
         # CLI validation
         if self.config.build_cli:
             errors += self._check_exists("src/cli/Cargo.toml", "CLI Cargo.toml")
             errors += self._check_exists("src/cli/src/main.rs", "CLI main.rs")
+            service_files = {
+                "src/cli/src/lib.rs": "Service lib.rs",
+                "src/cli/src/config.rs": "Service config.rs",
+                "src/cli/config.toml": "Service example config",
+                "src/cli/tests/service.rs": "Service integration test",
+            }
+            for path, description in service_files.items():
+                if self.config.service:
+                    errors += self._check_exists(path, description)
+                else:
+                    errors += self._check_not_exists(path, description)
         else:
             errors += self._check_not_exists("src/cli", "CLI directory")
         
+        # Tracing validation
+        logging_export = f"src/{self.config.project_name}/src/exports/logging.rs"
+        if self.config.tracing and self.config.build_c_libs:
+            errors += self._check_exists(logging_export, "Log callback export")
+        else:
+            errors += self._check_not_exists(logging_export, "Log callback export")
+        
+        if self.config.build_csharp_libs and self.config.build_c_libs:
+            if self.config.tracing:
+                errors += self._check_exists("src/bindings/csharp/NativeLog.cs", "C# log handler")
+                errors += self._check_exists("src/bindings/csharp/tests/NativeLogTests.cs", "C# log handler tests")
+            else:
+                errors += self._check_not_exists("src/bindings/csharp/NativeLog.cs", "C# log handler")
+        
+        # WebAssembly validation
+        if self.config.build_wasm:
+            errors += self._check_exists(f"src/{self.config.project_name}/src/wasm.rs", "wasm-bindgen exports")
+            errors += self._check_exists("src/bindings/wasm/Cargo.toml", "npm package crate")
+            errors += self._check_exists("src/.cargo/config.toml", "wasmtime test runner")
+        else:
+            errors += self._check_not_exists(f"src/{self.config.project_name}/src/wasm.rs", "wasm-bindgen exports")
+            errors += self._check_not_exists("src/bindings/wasm", "npm package crate")
+        
+        # Python bindings validation
+        python_files = {
+            "src/bindings/python/Cargo.toml": "Python bindings crate",
+            "src/bindings/python/pyproject.toml": "maturin project",
+            f"src/bindings/python/{self.config.project_name.replace('-', '_')}.pyi": "Python type stubs",
+            "src/bindings/python/tests/test_bindings.py": "pytest suite",
+        }
+        for path, description in python_files.items():
+            if self.config.build_python_libs:
+                errors += self._check_exists(path, description)
+            else:
+                errors += self._check_not_exists(path, description)
+        
+        # xtask validation
+        if self.config.xtask:
+            errors += self._check_exists("src/xtask/Cargo.toml", "xtask Cargo.toml")
+            errors += self._check_exists("src/xtask/src/main.rs", "xtask main.rs")
+            errors += self._check_exists("src/.cargo/config.toml", "cargo xtask alias")
+        else:
+            errors += self._check_not_exists("src/xtask", "xtask directory")
+            if not self.config.build_wasm:
+                errors += self._check_not_exists("src/.cargo", "cargo config directory")
+        
         # License validation
         errors += self._check_exists("LICENSE", "Main license file")
         errors += self._validate_license_content()
@@ -355,48 +614,8 @@ class TemplateValidator:
             return False
     
     def check_jinja2_remnants(self) -> bool:
-        """Check for unconverted Jinja2 template syntax.
-        
-        This method detects Jinja2 template remnants ({{ }} and {% %}) in generated files
-        while excluding GitHub Actions expressions (${{ }}) to avoid false positives.
-        """
-        logger.info("Checking for Jinja2 template remnants...")
-        
-        patterns = [r'\{%', r'\{\{']
-        extensions = ['*.yml', '*.yaml', '*.toml', '*.json', '*.rs', '*.md']
-        
-        remnants = []
-        for ext in extensions:
-            for file_path in self.project_path.rglob(ext):
-                # Skip vendor directories
-                if "vendor" in file_path.parts or ".git" in file_path.parts:
-                    continue
-                
-                try:
-                    content = file_path.read_text()
-                    for i, line in enumerate(content.splitlines(), 1):
-                        # Skip comments (simple heuristic)
-                        if line.strip().startswith('#') or line.strip().startswith('//'):
-                            continue
-                        
-                        # Skip lines with GitHub Actions expressions
-                        if '${{' in line:
-                            continue
-                        
-                        for pattern in patterns:
-                            if re.search(pattern, line):
-                                remnants.append(f"{file_path.relative_to(self.project_path)}:{i}: {line.strip()}")
-                except Exception as e:
-                    logger.warning(f"Could not read {file_path}: {e}")
-        
-        if remnants:
-            logger.error("✗ Found Jinja2 template remnants:")
-            for remnant in remnants:
-                logger.error(f"  {remnant}")
-            return False
-        else:
-            logger.info("✓ No Jinja2 remnants found")
-            return True
+        """Check for unconverted Jinja2 template syntax in the generated files."""
+        return check_jinja2_remnants(self.project_path, ['*.yml', '*.yaml', '*.toml', '*.json', '*.rs', '*.md'])
     
     def validate_builds(self) -> bool:
         """Run cargo check, build, and test."""
@@ -455,6 +674,367 @@ class TemplateValidator:
             return False
         logger.info("✓ cargo test passed")
         
+        # Check that the `cargo xtask` alias works
+        if self.config.xtask:
+            logger.info("Running cargo xtask --help...")
+            result = subprocess.run(
+                ["cargo", "xtask", "--help"],
+                cwd=src_dir,
+                capture_output=True,
+                text=True,
+                encoding='utf-8',
+                errors='replace'
+            )
+            if result.returncode != 0:
+                logger.error("✗ cargo xtask --help failed")
+                logger.error(result.stderr)
+                return False
+            logger.info("✓ cargo xtask --help passed")
+        
+        return True
+    
+    def validate_ci_gates(self) -> bool:
+        """Run the same checks as the generated `rust.yml`, so projects pass CI on their first PR."""
+        return run_ci_gates(self.project_path / "src")
+    
+    def validate_feature_tiers(self) -> bool:
+        """Test each `no_std` feature tier, and build the `no_std` tiers for a target without `std`."""
+        if self.config.no_std == "STD":
+            logger.debug("Skipping feature tier validation (no_std=STD)")
+            return True
+        
+        src_dir = self.project_path / "src"
+        package = ["-p", self.config.project_name, "--no-default-features", "--features"]
+        tiers = {"core": "", "alloc": "alloc", "std": "std"}
+        
+        for tier, features in tiers.items():
+            # Only the unit tests, as the `cdylib` and integration tests need `std`.
+            logger.info(f"Running unit tests for the {tier} tier...")
+            result = subprocess.run(
+                ["cargo", "test", "--lib", *package, features],
+                cwd=src_dir,
+                capture_output=True,
+                text=True,
+                encoding='utf-8',
+                errors='replace'
+            )
+            if result.returncode != 0:
+                logger.error(f"✗ Unit tests failed for the {tier} tier")
+                logger.error(result.stderr)
+                return False
+            logger.info(f"✓ Unit tests passed for the {tier} tier")
+        
+        installed = subprocess.run(
+            ["rustup", "target", "list", "--installed"],
+            capture_output=True,
+            text=True,
+            encoding='utf-8',
+            errors='replace'
+        )
+        if NO_STD_TARGET not in installed.stdout.split():
+            logger.warning(f"⚠ Skipping {NO_STD_TARGET} builds (run `rustup target add {NO_STD_TARGET}`)")
+            return True
+        
+        for tier in ["core", "alloc"]:
+            logger.info(f"Building the {tier} tier for {NO_STD_TARGET}...")
+            result = subprocess.run(
+                ["cargo", "build", *package, tiers[tier], "--target", NO_STD_TARGET],
+                cwd=src_dir,
+                capture_output=True,
+                text=True,
+                encoding='utf-8',
+                errors='replace'
+            )
+            if result.returncode != 0:
+                logger.error(f"✗ The {tier} tier failed to build for {NO_STD_TARGET}")
+                logger.error(result.stderr)
+                return False
+            logger.info(f"✓ The {tier} tier builds for {NO_STD_TARGET}")
+        
+        if self.config.build_c_libs:
+            # The C library without `std`. Release, as the panic handler needs `panic = "abort"`.
+            logger.info(f"Building the no_std static library for {NO_STD_TARGET}...")
+            result = subprocess.run(
+                ["cargo", "rustc", "--release", *package, "no-std-runtime",
+                 "--crate-type", "staticlib", "--target", NO_STD_TARGET],
+                cwd=src_dir,
+                capture_output=True,
+                text=True,
+                encoding='utf-8',
+                errors='replace'
+            )
+            if result.returncode != 0:
+                logger.error(f"✗ The no_std static library failed to build for {NO_STD_TARGET}")
+                logger.error(result.stderr)
+                return False
+            logger.info(f"✓ The no_std static library builds for {NO_STD_TARGET}")
+        
+        return True
+    
+    def validate_bare_metal(self) -> bool:
+        """Build the library and the `no_std` smoke test for the bare metal targets."""
+        if not self.config.bare_metal or self.config.no_std == "STD":
+            logger.debug("Skipping bare metal validation (bare_metal=false or no_std=STD)")
+            return True
+        
+        installed = subprocess.run(
+            ["rustup", "target", "list", "--installed"],
+            capture_output=True,
+            text=True,
+            encoding='utf-8',
+            errors='replace'
+        ).stdout.split()
+        
+        src_dir = self.project_path / "src"
+        package = ["-p", self.config.project_name, "--no-default-features"]
+        for target in BARE_METAL_TARGETS:
+            if target not in installed:
+                logger.warning(f"⚠ Skipping {target} builds (run `rustup target add {target}`)")
+                continue
+            
+            builds = {
+                "core tier": (src_dir, ["cargo", "build", *package]),
+                "alloc tier": (src_dir, ["cargo", "build", *package, "--features", "alloc"]),
+                "smoke test": (src_dir / "bare-metal-smoke", ["cargo", "build", "--release"]),
+            }
+            if self.config.build_c_libs:
+                # Release, as the panic handler needs `panic = "abort"`.
+                builds["static library"] = (src_dir, ["cargo", "rustc", "--release", *package,
+                    "--features", "no-std-runtime", "--crate-type", "staticlib"])
+            
+            for name, (cwd, cmd) in builds.items():
+                logger.info(f"Building the {name} for {target}...")
+                result = subprocess.run(
+                    [*cmd, "--target", target],
+                    cwd=cwd,
+                    capture_output=True,
+                    text=True,
+                    encoding='utf-8',
+                    errors='replace'
+                )
+                if result.returncode != 0:
+                    logger.error(f"✗ The {name} failed to build for {target}")
+                    logger.error(result.stderr)
+                    return False
+                logger.info(f"✓ The {name} builds for {target}")
+        
+        return True
+    
+    def validate_wasm(self) -> bool:
+        """Run the tests under wasmtime, and build the npm package's `cdylib` for the browser."""
+        if not self.config.build_wasm:
+            logger.debug("Skipping WebAssembly validation (build_wasm=false)")
+            return True
+        
+        installed = subprocess.run(
+            ["rustup", "target", "list", "--installed"],
+            capture_output=True,
+            text=True,
+            encoding='utf-8',
+            errors='replace'
+        ).stdout.split()
+        
+        src_dir = self.project_path / "src"
+        if WASI_TARGET not in installed:
+            logger.warning(f"⚠ Skipping wasmtime tests (run `rustup target add {WASI_TARGET}`)")
+        elif shutil.which("wasmtime") is None:
+            logger.warning("⚠ Skipping wasmtime tests (wasmtime is not installed)")
+        else:
+            # Only the unit tests, as the integration tests need files and processes.
+            logger.info(f"Running unit tests for {WASI_TARGET} under wasmtime...")
+            result = subprocess.run(
+                ["cargo", "test", "-p", self.config.project_name, "--lib", "--features", "wasm",
+                 "--target", WASI_TARGET],
+                cwd=src_dir,
+                capture_output=True,
+                text=True,
+                encoding='utf-8',
+                errors='replace'
+            )
+            if result.returncode != 0:
+                logger.error(f"✗ Unit tests failed for {WASI_TARGET}")
+                logger.error(result.stderr)
+                return False
+            logger.info(f"✓ Unit tests passed for {WASI_TARGET}")
+        
+        if WASM_TARGET not in installed:
+            logger.warning(f"⚠ Skipping npm package build (run `rustup target add {WASM_TARGET}`)")
+            return True
+        
+        logger.info(f"Building the npm package crate for {WASM_TARGET}...")
+        result = subprocess.run(
+            ["cargo", "build", "--release", "-p", f"{self.config.project_name}-wasm", "--target", WASM_TARGET],
+            cwd=src_dir,
+            capture_output=True,
+            text=True,
+            encoding='utf-8',
+            errors='replace'
+        )
+        if result.returncode != 0:
+            logger.error(f"✗ The npm package crate failed to build for {WASM_TARGET}")
+            logger.error(result.stderr)
+            return False
+        logger.info(f"✓ The npm package crate builds for {WASM_TARGET}")
+        
+        return True
+    
+    def validate_python(self) -> bool:
+        """Lint the Python bindings, and run the pytest suite against the built extension module."""
+        if not self.config.build_python_libs:
+            logger.debug("Skipping Python validation (build_python_libs=false)")
+            return True
+        
+        # Its own workspace, see `src/bindings/python/Cargo.toml`.
+        python_dir = self.project_path / "src" / "bindings" / "python"
+        for name, cmd in {
+            "build": ["cargo", "build"],
+            "clippy": ["cargo", "clippy", "--all-targets", "--", "-D", "warnings"],
+        }.items():
+            logger.info(f"Running {name} for the Python bindings...")
+            result = subprocess.run(
+                cmd,
+                cwd=python_dir,
+                capture_output=True,
+                text=True,
+                encoding='utf-8',
+                errors='replace'
+            )
+            if result.returncode != 0:
+                logger.error(f"✗ {name} failed for the Python bindings")
+                logger.error(result.stderr)
+                return False
+        logger.info("✓ The Python bindings build")
+        
+        has_pytest = subprocess.run(
+            [sys.executable, "-c", "import pytest"],
+            capture_output=True
+        ).returncode == 0
+        if not has_pytest:
+            logger.warning("⚠ Skipping pytest suite (pytest is not installed)")
+            return True
+        
+        # Copies the library under the module's name instead of building a wheel, so maturin isn't needed.
+        crate_name = self.config.project_name.replace('-', '_')
+        built = next(
+            path for path in (python_dir / "target" / "debug").iterdir()
+            if path.suffix in (".so", ".dylib", ".dll") and f"{crate_name}_python" in path.name
+        )
+        module_dir = python_dir / "target" / "pytest-module"
+        module_dir.mkdir(exist_ok=True)
+        shutil.copy(built, module_dir / f"{crate_name}{PYTHON_MODULE_SUFFIX}")
+        
+        logger.info("Running pytest suite...")
+        result = subprocess.run(
+            [sys.executable, "-m", "pytest", "-p", "no:cacheprovider"],
+            cwd=python_dir,
+            env={**os.environ, "PYTHONPATH": str(module_dir)},
+            capture_output=True,
+            text=True,
+            encoding='utf-8',
+            errors='replace'
+        )
+        if result.returncode != 0:
+            logger.error("✗ pytest suite failed")
+            logger.error(result.stdout)
+            return False
+        logger.info("✓ pytest suite passed")
+        
+        return True
+    
+    def validate_runtime_identifiers(self) -> bool:
+        """Check the C# resolver, `artifact-groups.yml` and the `rust.yml` matrix agree on the .NET RIDs."""
+        if not (self.config.build_c_libs and self.config.build_csharp_libs):
+            logger.info("Skipping runtime identifier validation (C# bindings disabled)")
+            return True
+        
+        logger.info("Validating .NET runtime identifiers...")
+        errors = 0
+        
+        with open(self.project_path / ".github/workflows/rust.yml", 'r') as f:
+            workflow = yaml.safe_load(f)
+        targets = [entry["target"] for entry in workflow["jobs"]["build-and-test"]["strategy"]["matrix"]["include"]]
+        expected = [TARGET_RIDS[target] for target in targets if target in TARGET_RIDS]
+        
+        # The library name must be free of target triples after all renames are applied, in order.
+        with open(self.project_path / ".github/artifact-groups.yml", 'r') as f:
+            groups = yaml.safe_load(f)
+        renames = [item for rename in groups["C-Library"]["renames"] for item in rename.items()]
+        for target in targets:
+            if target not in TARGET_RIDS:
+                continue
+            name = f"C-Library-{target}"
+            for old, new in renames:
+                name = name.replace(old, new)
+            if name != TARGET_RIDS[target]:
+                logger.error(f"✗ artifact-groups.yml renames {target} to '{name}', expected '{TARGET_RIDS[target]}'")
+                errors += 1
+        
+        native_methods = (self.project_path / "src/bindings/csharp/NativeMethods.cs").read_text()
+        match = re.search(r"PackagedRuntimeIdentifiers = new\[\]\s*\{(.*?)\};", native_methods, re.S)
+        packaged = re.findall(r'"([^"]+)"', match.group(1)) if match else []
+        if packaged != expected:
+            logger.error(f"✗ NativeMethods.cs packages {packaged}, but rust.yml builds {expected}")
+            errors += 1
+        
+        if errors == 0:
+            logger.info(f"✓ Runtime identifier validation passed ({len(expected)} RIDs)")
+            return True
+        else:
+            logger.error(f"✗ Runtime identifier validation failed with {errors} error(s)")
+            return False
+    
+    def validate_c_abi(self) -> bool:
+        """Check that the C headers agree with Rust, including on big-endian targets if enabled."""
+        if not self.config.build_c_libs:
+            logger.debug("Skipping C ABI validation (build_c_libs=false)")
+            return True
+        
+        src_dir = self.project_path / "src"
+        test_args = ["test", "--features", "c-exports", "--test", "c_abi"]
+        
+        logger.info("Running C ABI tests...")
+        result = subprocess.run(
+            ["cargo", *test_args],
+            cwd=src_dir,
+            capture_output=True,
+            text=True,
+            encoding='utf-8',
+            errors='replace'
+        )
+        if result.returncode != 0:
+            logger.error("✗ C ABI tests failed")
+            logger.error(result.stdout)
+            logger.error(result.stderr)
+            return False
+        logger.info("✓ C ABI tests passed")
+        
+        if not self.config.big_endian:
+            return True
+        
+        if shutil.which("cross") is None:
+            logger.warning("⚠ Skipping big-endian C ABI tests (cross is not installed)")
+            return True
+        
+        # Cross only mounts the workspace, see `src/Cross.toml`.
+        env = {**os.environ, "C_ABI_CONFIG_DIR": str(self.project_path / ".github")}
+        for target in ["powerpc-unknown-linux-gnu", "powerpc64-unknown-linux-gnu"]:
+            logger.info(f"Running C ABI tests for {target}...")
+            result = subprocess.run(
+                ["cross", *test_args, "--target", target],
+                cwd=src_dir,
+                env=env,
+                capture_output=True,
+                text=True,
+                encoding='utf-8',
+                errors='replace'
+            )
+            if result.returncode != 0:
+                logger.error(f"✗ C ABI tests failed for {target}")
+                logger.error(result.stdout)
+                logger.error(result.stderr)
+                return False
+            logger.info(f"✓ C ABI tests passed for {target}")
+        
         return True
     
     def validate_mkdocs(self) -> bool:
@@ -494,65 +1074,40 @@ def generate_project(config: TemplateTestConfig, temp_dir: Path) -> Tuple[bool,
     logger.info("Generating project with cargo-generate...")
     logger.info(f"Configuration: project={config.project_name}, mkdocs={config.mkdocs}, vscode={config.vscode}")
     
-    # Get repository root (two levels up from .github/tests/)
-    script_dir = Path(__file__).parent
-    repo_root = script_dir.parent.parent
-    template_path = repo_root / "templates" / "general"
-    
-    if not template_path.exists():
-        logger.error(f"Template path not found: {template_path}")
-        return False, None
-    
-    # Build cargo-generate command
-    # FIX: Use --name for project name and --destination for output directory
-    cmd = [
-        "cargo", "generate",  # Two separate words, not hyphenated
-        "--path", str(template_path),
-        "--name", config.project_name,  # Project name (NOT a path)
-        "--destination", str(temp_dir),  # Where to generate
-        "--define", f"gh_username=test-user",
-        "--define", f"gh_reponame=test-repo",
-        "--define", f"project_description=Test project for template validation",
-        "--define", f"mkdocs={str(config.mkdocs).lower()}",
-        "--define", f"vscode={str(config.vscode).lower()}",
-        "--define", f"xplat={str(config.xplat).lower()}",
-        "--define", f"wine={str(config.wine).lower()}",
-        "--define", f"bench={str(config.bench).lower()}",
-        "--define", f"miri={str(config.miri).lower()}",
-        "--define", f"fuzz={str(config.fuzz).lower()}",
-        "--define", f"build_c_libs={str(config.build_c_libs).lower()}",
-        "--define", f"build_cli={str(config.build_cli).lower()}",
-        "--define", f"publish_crate_on_tag={str(config.publish_crate_on_tag).lower()}",
-        "--define", f"license={config.license}",
-        "--define", f"no_std_support={config.no_std}",
+    defines = [
+        "gh_username=test-user",
+        "gh_reponame=test-repo",
+        "project_description=Test project for template validation",
+        f"mkdocs={str(config.mkdocs).lower()}",
+        f"vscode={str(config.vscode).lower()}",
+        f"xplat={str(config.xplat).lower()}",
+        f"bare_metal={str(config.bare_metal).lower()}",
+        f"wine={str(config.wine).lower()}",
+        f"bench={str(config.bench).lower()}",
+        f"miri={str(config.miri).lower()}",
+        f"fuzz={str(config.fuzz).lower()}",
+        f"build_c_libs={str(config.build_c_libs).lower()}",
+        f"project_kind={config.project_kind}",
+        f"xtask={str(config.xtask).lower()}",
+        f"tracing={str(config.tracing).lower()}",
+        f"build_wasm={str(config.build_wasm).lower()}",
+        f"build_python_libs={str(config.build_python_libs).lower()}",
+        f"publish_crate_on_tag={str(config.publish_crate_on_tag).lower()}",
+        f"license={config.license}",
+        f"no_std_support={config.no_std}",
     ]
     
     # Add conditional parameters
     if config.xplat:
-        cmd.extend(["--define", f"big_endian={str(config.big_endian).lower()}"])
+        defines.append(f"big_endian={str(config.big_endian).lower()}")
     
     if config.build_c_libs:
-        cmd.extend(["--define", f"build_csharp_libs={str(config.build_csharp_libs).lower()}"])
+        defines.append(f"build_csharp_libs={str(config.build_csharp_libs).lower()}")
     
     if config.bench and (config.build_c_libs or config.build_cli):
-        cmd.extend(["--define", f"build_with_pgo={str(config.build_with_pgo).lower()}"])
-    
-    logger.debug(f"Running: {' '.join(cmd)}")
+        defines.append(f"build_with_pgo={str(config.build_with_pgo).lower()}")
     
-    # Run cargo-generate
-    result = subprocess.run(cmd, capture_output=True, text=True, encoding='utf-8', errors='replace')
-    if result.returncode != 0:
-        logger.error("✗ cargo-generate failed")
-        logger.error(result.stderr)
-        return False, None
-    
-    generated_path = temp_dir / config.project_name
-    if not generated_path.exists():
-        logger.error(f"✗ Generated project not found at {generated_path}")
-        return False, None
-    
-    logger.info(f"✓ Project generated successfully at {generated_path}")
-    return True, generated_path
+    return cargo_generate("general", config.project_name, temp_dir, defines)
 
 
 def parse_args() -> argparse.Namespace:
@@ -593,6 +1148,12 @@ def parse_args() -> argparse.Namespace:
         default=False,
         help="Include big-endian support (default: false)"
     )
+    parser.add_argument(
+        "--bare-metal",
+        type=lambda x: x.lower() == "true",
+        default=False,
+        help="Include bare metal build checks, needs a no_std option (default: false)"
+    )
     parser.add_argument(
         "--wine",
         type=lambda x: x.lower() == "true",
@@ -629,6 +1190,18 @@ def parse_args() -> argparse.Namespace:
         default=False,
         help="Build C# bindings (default: false)"
     )
+    parser.add_argument(
+        "--build-wasm",
+        type=lambda x: x.lower() == "true",
+        default=False,
+        help="Build WebAssembly exports and npm package (default: false)"
+    )
+    parser.add_argument(
+        "--build-python-libs",
+        type=lambda x: x.lower() == "true",
+        default=False,
+        help="Build Python bindings with PyO3 (default: false)"
+    )
     parser.add_argument(
         "--build-with-pgo",
         type=lambda x: x.lower() == "true",
@@ -636,10 +1209,22 @@ def parse_args() -> argparse.Namespace:
         help="Enable PGO (Profile Guided Optimization) (default: true)"
     )
     parser.add_argument(
-        "--build-cli",
+        "--project-kind",
+        choices=["library", "cli", "service"],
+        default="library",
+        help="Project kind: library, cli or service (default: library)"
+    )
+    parser.add_argument(
+        "--xtask",
+        type=lambda x: x.lower() == "true",
+        default=True,
+        help="Include xtask automation crate (default: true)"
+    )
+    parser.add_argument(
+        "--tracing",
         type=lambda x: x.lower() == "true",
         default=False,
-        help="Include CLI executable wrapper project (default: false)"
+        help="Include tracing in the library, CLI and C exports (default: false)"
     )
     parser.add_argument(
         "--publish-crate-on-tag",
@@ -686,6 +1271,13 @@ def main() -> int:
         all_passed &= validator.validate_file_formats()
         all_passed &= validator.check_jinja2_remnants()
         all_passed &= validator.validate_builds()
+        all_passed &= validator.validate_ci_gates()
+        all_passed &= validator.validate_feature_tiers()
+        all_passed &= validator.validate_bare_metal()
+        all_passed &= validator.validate_wasm()
+        all_passed &= validator.validate_python()
+        all_passed &= validator.validate_runtime_identifiers()
+        all_passed &= validator.validate_c_abi()
         all_passed &= validator.validate_mkdocs()
         
         if all_passed:
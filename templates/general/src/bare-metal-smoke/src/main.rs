@@ -0,0 +1,82 @@
+//! Checks that {{project-name}} links on bare metal targets, without `std`.
+//!
+//! Only built, never run, as CI has nothing to run it on. See `build-bare-metal` in
+//! `.github/workflows/rust.yml`.
+//!
+//! ```bash
+//! cd src/bare-metal-smoke
+//! cargo build --release
+//! cargo build --release --target riscv32imac-unknown-none-elf
+//! ```
+
+#![no_std]
+#![no_main]
+
+use core::alloc::{GlobalAlloc, Layout};
+use core::cell::UnsafeCell;
+use core::hint::black_box;
+use core::panic::PanicInfo;
+use core::ptr::null_mut;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use {{crate_name}}::{add, add_to_all, Counter};
+
+/// Entry point. Real firmware gets its startup code from a crate like `cortex-m-rt` or `riscv-rt`.
+#[no_mangle]
+pub extern "C" fn _start() -> ! {
+    // `black_box` stops the calls from being optimized out, so the library is really linked.
+    black_box(add(black_box(2), 2));
+    black_box(add_to_all(black_box(&[1, 2, 3]), 1));
+
+    let mut counter = Counter::new();
+    black_box(counter.increment());
+
+    loop {
+        core::hint::spin_loop();
+    }
+}
+
+#[panic_handler]
+fn panic(_: &PanicInfo<'_>) -> ! {
+    loop {
+        core::hint::spin_loop();
+    }
+}
+
+const HEAP_SIZE: usize = 4096;
+
+/// Hands out memory from a fixed buffer and never frees it. Enough for the `alloc` tier.
+struct BumpAllocator {
+    heap: UnsafeCell<[u8; HEAP_SIZE]>,
+    next: AtomicUsize,
+}
+
+// `next` is only moved forward atomically, so no two allocations share memory.
+unsafe impl Sync for BumpAllocator {}
+
+unsafe impl GlobalAlloc for BumpAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let base = self.heap.get() as usize;
+        let mut start = 0;
+        let reserved = self
+            .next
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |next| {
+                start = (base + next).next_multiple_of(layout.align()) - base;
+                let end = start.checked_add(layout.size())?;
+                (end <= HEAP_SIZE).then_some(end)
+            });
+
+        match reserved {
+            Ok(_) => self.heap.get().cast::<u8>().add(start),
+            Err(_) => null_mut(),
+        }
+    }
+
+    unsafe fn dealloc(&self, _: *mut u8, _: Layout) {}
+}
+
+#[global_allocator]
+static ALLOCATOR: BumpAllocator = BumpAllocator {
+    heap: UnsafeCell::new([0; HEAP_SIZE]),
+    next: AtomicUsize::new(0),
+};
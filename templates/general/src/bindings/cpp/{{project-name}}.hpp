@@ -0,0 +1,316 @@
+#ifdef _MSC_VER
+    /* MSVC can't pack a single struct, so `#[repr(packed)]` types are not supported there.
+       There's no global `#pragma pack`, so other structs keep the default C alignment, which
+       matches `#[repr(C)]`. `c_layouts_match_rust` in `tests/c_abi.rs` checks this. */
+    #define PACKED
+#else
+    #define PACKED __attribute__((packed))
+#endif
+
+
+#ifndef {{crate_name}}
+#define {{crate_name}}
+
+/* Warning, this file is autogenerated by cbindgen. Don't modify this manually. */
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+namespace {{crate_name}} {
+
+/// Status code returned by fallible exports.
+enum class FfiStatus {
+  /// The operation completed successfully.
+  Ok = 0,
+  /// The operation failed. Check the last error message for details.
+  Error = 1,
+  /// A required pointer argument was null.
+  NullPointer = 2,
+  /// The provided buffer was too small to hold the result.
+  BufferTooSmall = 3,
+  /// The library panicked. Check the last error message for details.
+  Panic = 4,
+  /// A handle was invalid, e.g. it was already freed.
+  InvalidHandle = 5,
+  /// A string argument was not valid UTF-8.
+  InvalidUtf8 = 6,
+};
+{%- if tracing %}
+
+/// Severity of a log message, from most to least severe.
+enum class FfiLogLevel {
+  /// Something failed.
+  Error = 1,
+  /// Something unexpected happened, but the library recovered.
+  Warn = 2,
+  /// High level progress.
+  Info = 3,
+  /// Details useful when debugging.
+  Debug = 4,
+  /// Very verbose details, such as function return values.
+  Trace = 5,
+};
+{%- endif %}
+
+/// A simple counter, used as an example of a stateful object.
+struct Counter;
+{%- if tracing %}
+
+/// Receives a log message, or null for no callback.
+///
+/// `message` points to `len` bytes of UTF-8 text, followed by a null terminator.
+/// It is only valid for the duration of the call.
+///
+/// The callback may be called from any thread, and must not unwind.
+using LogCallback = void(*)(FfiLogLevel level, const char *message, uintptr_t len);
+{%- endif %}
+{%- if std-by-default or no_std-by-default %}
+
+/// Memory and panic functions provided by the host.
+///
+/// All of them are required. `{{crate_name}}_init_runtime` rejects hooks with a null function.
+struct FfiHooks {
+  /// Allocates `size` bytes aligned to `align`, a power of two. Returns null on failure.
+  void *(*Alloc)(uintptr_t size, uintptr_t align);
+  /// Frees memory returned by `alloc`, with the same `size` and `align`.
+  void (*Free)(void *ptr, uintptr_t size, uintptr_t align);
+  /// Called when the library panics, with `len` bytes of UTF-8 text followed by a null terminator.
+  ///
+  /// Must not return, e.g. call `abort` or reset the device. The library can't continue after a
+  /// panic, so if it does return, the library aborts the process, or traps without an OS.
+  void (*Panic)(const char *message, uintptr_t len);
+
+  FfiHooks(void *(*const& alloc)(uintptr_t size, uintptr_t align),
+           void (*const& free)(void *ptr, uintptr_t size, uintptr_t align),
+           void (*const& panic)(const char *message, uintptr_t len))
+    : Alloc(alloc),
+      Free(free),
+      Panic(panic)
+  {}
+
+};
+{%- endif %}
+
+/// Version of the library, so callers can check that they loaded a compatible build.
+struct Version {
+  /// Major version. Changes when the C API breaks.
+  uint32_t Major;
+  /// Minor version.
+  uint32_t Minor;
+  /// Patch version.
+  uint32_t Patch;
+  /// `true` for pre-release versions such as `1.0.0-beta.1`.
+  bool PreRelease;
+
+  Version(uint32_t const& major,
+          uint32_t const& minor,
+          uint32_t const& patch,
+          bool const& preRelease)
+    : Major(major),
+      Minor(minor),
+      Patch(patch),
+      PreRelease(preRelease)
+  {}
+
+};
+
+
+extern "C" {
+
+ int32_t it_works() ;
+
+/// Adds two numbers together, failing if the result overflows.
+///
+/// # Parameters
+///
+/// - `left`: The first number.
+/// - `right`: The second number.
+/// - `out_result`: Receives the sum on success.
+///
+/// # Returns
+///
+/// - [`FfiStatus::Ok`] on success.
+/// - [`FfiStatus::Error`] if the sum overflows.
+/// - [`FfiStatus::NullPointer`] if `out_result` is null.
+/// - [`FfiStatus::Panic`] if the library panicked.
+///
+/// # Safety
+///
+/// `out_result` must be valid for writes.
+ FfiStatus {{crate_name}}_checked_add(uint64_t left, uint64_t right, uint64_t *outResult) ;
+
+/// Creates a new counter starting at zero.
+///
+/// # Returns
+///
+/// An owning pointer to the counter, or null if creation failed.
+/// The counter must be released with [`{{crate_name}}_counter_free`].
+ Counter *{{crate_name}}_counter_new() ;
+
+/// Releases a counter created by [`{{crate_name}}_counter_new`].
+///
+/// # Returns
+///
+/// - [`FfiStatus::Ok`] if the counter was released.
+/// - [`FfiStatus::NullPointer`] if `counter` is null.
+/// - [`FfiStatus::InvalidHandle`] if `counter` was already released (debug builds only).
+///
+/// # Safety
+///
+/// `counter` must be null or a pointer returned by [`{{crate_name}}_counter_new`].
+/// It must not be used after this call.
+ FfiStatus {{crate_name}}_counter_free(Counter *counter) ;
+
+/// Increments the counter.
+///
+/// # Parameters
+///
+/// - `counter`: The counter to increment.
+/// - `out_value`: Receives the new value on success.
+///
+/// # Safety
+///
+/// `counter` must be a live pointer returned by [`{{crate_name}}_counter_new`].
+/// `out_value` must be valid for writes.
+ FfiStatus {{crate_name}}_counter_increment(Counter *counter, uint64_t *outValue) ;
+
+/// Gets the current value of the counter.
+///
+/// # Parameters
+///
+/// - `counter`: The counter to read.
+/// - `out_value`: Receives the current value on success.
+///
+/// # Safety
+///
+/// `counter` must be a live pointer returned by [`{{crate_name}}_counter_new`].
+/// `out_value` must be valid for writes.
+ FfiStatus {{crate_name}}_counter_value(const Counter *counter, uint64_t *outValue) ;
+
+/// Returns the length of the last error message in bytes, including the null terminator.
+///
+/// # Returns
+///
+/// `0` if no error has been recorded on the current thread.
+ uintptr_t {{crate_name}}_last_error_length() ;
+
+/// Copies the last error message, including the null terminator, into `buffer`.
+///
+/// # Parameters
+///
+/// - `buffer`: Buffer to write the message to.
+/// - `buffer_len`: Length of `buffer` in bytes. Use [`{{crate_name}}_last_error_length`] to size it.
+///
+/// # Returns
+///
+/// - [`FfiStatus::Ok`] if the message was copied. An empty string is written if there is no error.
+/// - [`FfiStatus::NullPointer`] if `buffer` is null.
+/// - [`FfiStatus::BufferTooSmall`] if `buffer_len` is smaller than the message length.
+///
+/// # Safety
+///
+/// `buffer` must be valid for writes of `buffer_len` bytes.
+ FfiStatus {{crate_name}}_last_error_message(char *buffer, uintptr_t bufferLen) ;
+
+/// Releases a string returned by this library.
+///
+/// # Safety
+///
+/// `value` must be null or a string returned by this library that has not been freed yet.
+ void {{crate_name}}_free_string(char *value) ;
+
+/// Creates a greeting for `name`.
+///
+/// # Returns
+///
+/// A string that must be released with [`{{crate_name}}_free_string`],
+/// or null if `name` is null or not valid UTF-8.
+///
+/// # Safety
+///
+/// `name` must be null or a null-terminated string.
+ char *{{crate_name}}_greet(const char *name) ;
+
+/// Writes a greeting for `name` into a caller-provided buffer.
+///
+/// # Parameters
+///
+/// - `name`: Null-terminated UTF-8 name.
+/// - `buffer`: Buffer to write the greeting to. May be null if `buffer_len` is zero.
+/// - `buffer_len`: Length of `buffer` in bytes.
+/// - `out_required`: Receives the length needed, including the null terminator. May be null.
+///
+/// # Returns
+///
+/// - [`FfiStatus::Ok`] if the greeting was written.
+/// - [`FfiStatus::BufferTooSmall`] if `buffer_len` is smaller than the required length.
+/// - [`FfiStatus::NullPointer`] if `name` is null.
+/// - [`FfiStatus::InvalidUtf8`] if `name` is not valid UTF-8.
+///
+/// # Safety
+///
+/// `name` must be null or a null-terminated string.
+/// `buffer` must be valid for writes of `buffer_len` bytes.
+/// `out_required` must be null or valid for writes.
+ FfiStatus {{crate_name}}_greet_into(const char *name, char *buffer, uintptr_t bufferLen, uintptr_t *outRequired) ;
+
+/// Sums all bytes in `data`.
+///
+/// # Parameters
+///
+/// - `data`: Bytes to sum. May be null if `data_len` is zero.
+/// - `data_len`: Number of bytes in `data`.
+/// - `out_sum`: Receives the sum on success.
+///
+/// # Safety
+///
+/// `data` must be valid for reads of `data_len` bytes. `out_sum` must be valid for writes.
+ FfiStatus {{crate_name}}_sum_bytes(const uint8_t *data, uintptr_t dataLen, uint64_t *outSum) ;
+{%- if tracing %}
+
+/// Sets the callback that receives the library's log messages, replacing the previous one.
+///
+/// # Parameters
+///
+/// - `callback`: Function called for every message, or null to stop logging.
+///
+/// # Returns
+///
+/// - [`FfiStatus::Ok`] if the callback was set.
+/// - [`FfiStatus::Error`] if another `tracing` subscriber is already installed.
+/// - [`FfiStatus::Panic`] if the library panicked.
+ FfiStatus {{crate_name}}_set_log_callback(LogCallback callback) ;
+{%- endif %}
+{%- if std-by-default or no_std-by-default %}
+
+/// Sets the allocator and panic handler used by the library. Call it once, before any other export.
+///
+/// # Parameters
+///
+/// - `hooks`: The host's functions, copied by the library.
+///
+/// # Returns
+///
+/// - [`FfiStatus::Ok`] if the hooks were set.
+/// - [`FfiStatus::Error`] if the hooks were already set.
+/// - [`FfiStatus::NullPointer`] if `hooks`, or any function in it, is null.
+///
+/// # Safety
+///
+/// `hooks` must be null or valid for reads.
+ FfiStatus {{crate_name}}_init_runtime(const FfiHooks *hooks) ;
+{%- endif %}
+
+/// Returns the version of the library, as set in `Cargo.toml`.
+ Version {{crate_name}}_version() ;
+
+}  // extern "C"
+
+}  // namespace {{crate_name}}
+
+#endif  // {{crate_name}}
+
+/* Text to put at the end of the generated file */
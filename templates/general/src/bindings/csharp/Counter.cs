@@ -0,0 +1,76 @@
+using System;
+using {{crate_name}}.Net.Sys;
+
+namespace {{crate_name}}.Net;
+
+/// <summary>
+///     A counter owned by the native library, released when disposed or finalized.
+/// </summary>
+/// <remarks>
+///     Calls are serialized, so a counter can be shared between threads. Each call also holds a reference
+///     to the handle, so disposing or finalizing the counter never frees it while native code is using it.
+/// </remarks>
+public sealed unsafe class Counter : IDisposable
+{
+    private readonly CounterHandle _handle = CounterHandle.Create();
+    private readonly object _lock = new();
+
+    /// <summary>
+    ///     The current value of the counter.
+    /// </summary>
+    /// <exception cref="ObjectDisposedException">The counter was disposed.</exception>
+    public ulong Value
+    {
+        get
+        {
+            lock (_lock)
+            {
+                var added = false;
+                try
+                {
+                    _handle.DangerousAddRef(ref added);
+                    ulong value;
+                    NativeMethods.{{crate_name}}_counter_value(_handle.Pointer, &value).ThrowIfFailed();
+                    return value;
+                }
+                finally
+                {
+                    if (added)
+                        _handle.DangerousRelease();
+                }
+            }
+        }
+    }
+
+    /// <summary>
+    ///     Increments the counter, returning the new value.
+    /// </summary>
+    /// <exception cref="ObjectDisposedException">The counter was disposed.</exception>
+    public ulong Increment()
+    {
+        lock (_lock)
+        {
+            var added = false;
+            try
+            {
+                _handle.DangerousAddRef(ref added);
+                ulong value;
+                NativeMethods.{{crate_name}}_counter_increment(_handle.Pointer, &value).ThrowIfFailed();
+                return value;
+            }
+            finally
+            {
+                if (added)
+                    _handle.DangerousRelease();
+            }
+        }
+    }
+
+    /// <summary>
+    ///     Releases the native counter. Later calls throw <see cref="ObjectDisposedException"/>.
+    /// </summary>
+    public void Dispose()
+    {
+        _handle.Dispose();
+    }
+}
@@ -0,0 +1,47 @@
+using System;
+using System.Runtime.InteropServices;
+
+namespace {{crate_name}}.Net.Sys;
+
+/// <summary>
+///     Opaque native counter. Only ever used behind a pointer.
+/// </summary>
+public unsafe partial struct Counter
+{
+}
+
+/// <summary>
+///     Owns a native <see cref="Counter"/> and releases it when disposed or finalized.
+/// </summary>
+public sealed unsafe class CounterHandle : SafeHandle
+{
+    private CounterHandle() : base(IntPtr.Zero, true) { }
+
+    /// <inheritdoc />
+    public override bool IsInvalid => handle == IntPtr.Zero;
+
+    /// <summary>
+    ///     Pointer to pass to native methods. Only valid while this handle is alive.
+    /// </summary>
+    public Counter* Pointer => (Counter*)handle;
+
+    /// <summary>
+    ///     Creates a new native counter.
+    /// </summary>
+    /// <exception cref="OutOfMemoryException">The native counter could not be created.</exception>
+    public static CounterHandle Create()
+    {
+        var result = new CounterHandle();
+        result.SetHandle((IntPtr)NativeMethods.{{crate_name}}_counter_new());
+        if (result.IsInvalid)
+            throw new OutOfMemoryException("Failed to create native counter.");
+
+        return result;
+    }
+
+    /// <inheritdoc />
+    protected override bool ReleaseHandle()
+    {
+        return NativeMethods.{{crate_name}}_counter_free((Counter*)handle) == FfiStatus.Ok;
+    }
+}
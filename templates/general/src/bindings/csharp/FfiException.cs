@@ -0,0 +1,51 @@
+using System;
+using {{crate_name}}.Net.Sys;
+
+namespace {{crate_name}}.Net;
+
+/// <summary>
+///     Thrown when the native library fails or panics, with the message it recorded.
+/// </summary>
+public class FfiException : Exception
+{
+    /// <summary>
+    ///     Creates an exception for a failed native call.
+    /// </summary>
+    /// <param name="status">Status returned by the native library.</param>
+    /// <param name="message">Error message recorded by the native library.</param>
+    public FfiException(FfiStatus status, string message) : base(message)
+    {
+        Status = status;
+    }
+
+    /// <summary>
+    ///     Status returned by the native library, e.g. <see cref="FfiStatus.Panic"/>.
+    /// </summary>
+    public FfiStatus Status { get; }
+}
+
+internal static class FfiStatusExtensions
+{
+    /// <summary>
+    ///     Throws the .NET exception matching <paramref name="status"/>, unless it is <see cref="FfiStatus.Ok"/>.
+    /// </summary>
+    /// <remarks>
+    ///     The native library only records a message for <see cref="FfiStatus.Error"/>, <see cref="FfiStatus.Panic"/>
+    ///     and <see cref="FfiStatus.InvalidUtf8"/>, so the other statuses use a fixed message.
+    /// </remarks>
+    public static void ThrowIfFailed(this FfiStatus status)
+    {
+        if (status == FfiStatus.Ok)
+            return;
+
+        Exception exception = status switch
+        {
+            FfiStatus.NullPointer => new ArgumentNullException(null, "A required pointer was null."),
+            FfiStatus.BufferTooSmall => new ArgumentException("The buffer is too small for the result."),
+            FfiStatus.InvalidHandle => new ObjectDisposedException(null, "The native handle was already released."),
+            FfiStatus.InvalidUtf8 => new ArgumentException(LastError.Message()),
+            _ => new FfiException(status, LastError.Message()),
+        };
+        throw exception;
+    }
+}
@@ -0,0 +1,27 @@
+using System.Text;
+
+namespace {{crate_name}}.Net.Sys;
+
+/// <summary>
+///     Reads the error message recorded by the native library on the current thread.
+/// </summary>
+internal static unsafe class LastError
+{
+    /// <summary>
+    ///     Returns the last error message, or <c>unknown error</c> if none was recorded.
+    /// </summary>
+    public static string Message()
+    {
+        var length = NativeMethods.{{crate_name}}_last_error_length();
+        if (length == 0)
+            return "unknown error";
+
+        var buffer = new byte[(int)length];
+        fixed (byte* bufferPtr = buffer)
+        {
+            NativeMethods.{{crate_name}}_last_error_message(bufferPtr, length);
+        }
+
+        return Encoding.UTF8.GetString(buffer, 0, buffer.Length - 1);
+    }
+}
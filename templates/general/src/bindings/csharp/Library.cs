@@ -0,0 +1,176 @@
+using System;
+using System.Runtime.InteropServices;
+using System.Text;
+using {{crate_name}}.Net.Sys;
+
+namespace {{crate_name}}.Net;
+
+/// <summary>
+///     Safe wrappers for the native library's functions.
+/// </summary>
+/// <remarks>
+///     Pointers, buffers and status codes are handled here, so callers use strings, spans and exceptions.
+///     The raw functions are in <see cref="NativeMethods"/>.
+/// </remarks>
+public static unsafe class Library
+{
+    /// <summary>
+    ///     Version of the native library, as set in its <c>Cargo.toml</c>.
+    /// </summary>
+    /// <remarks>Pre-release versions like <c>1.0.0-beta.1</c> are reported as <c>1.0.0</c>, see <see cref="IsPreRelease"/>.</remarks>
+    public static System.Version Version
+    {
+        get
+        {
+            var version = NativeMethods.{{crate_name}}_version();
+            return new System.Version((int)version.major, (int)version.minor, (int)version.patch);
+        }
+    }
+
+    /// <summary>
+    ///     <c>true</c> if the native library is a pre-release version, such as <c>1.0.0-beta.1</c>.
+    /// </summary>
+    public static bool IsPreRelease => NativeMethods.{{crate_name}}_version().pre_release;
+
+    /// <summary>
+    ///     Adds two numbers together.
+    /// </summary>
+    /// <exception cref="OverflowException">The sum doesn't fit in a <see cref="ulong"/>.</exception>
+    public static ulong CheckedAdd(ulong left, ulong right)
+    {
+        ulong result;
+        var status = NativeMethods.{{crate_name}}_checked_add(left, right, &result);
+        if (status == FfiStatus.Error)
+            throw new OverflowException(LastError.Message());
+
+        status.ThrowIfFailed();
+        return result;
+    }
+
+    /// <summary>
+    ///     Creates a greeting for <paramref name="name"/>.
+    /// </summary>
+    /// <exception cref="ArgumentNullException"><paramref name="name"/> is <c>null</c>.</exception>
+    /// <exception cref="ArgumentException"><paramref name="name"/> contains a null character.</exception>
+    public static string Greet(string name)
+    {
+        return Greet(ToNullTerminated(name, nameof(name)));
+    }
+
+    /// <summary>
+    ///     Creates a greeting for <paramref name="utf8Name"/>, a UTF-8 encoded name.
+    /// </summary>
+    /// <exception cref="ArgumentException">
+    ///     <paramref name="utf8Name"/> contains a null byte, or is not valid UTF-8.
+    /// </exception>
+    public static string Greet(ReadOnlySpan<byte> utf8Name)
+    {
+        return Greet(ToNullTerminated(utf8Name, nameof(utf8Name)));
+    }
+
+    /// <summary>
+    ///     Writes a UTF-8 greeting for <paramref name="name"/> into <paramref name="destination"/>,
+    ///     followed by a null terminator.
+    /// </summary>
+    /// <param name="name">The name to greet.</param>
+    /// <param name="destination">Buffer for the greeting. Needs one byte more than the greeting, for the terminator.</param>
+    /// <param name="bytesWritten">Length of the greeting in bytes, without the terminator. <c>0</c> on failure.</param>
+    /// <returns><c>false</c> if <paramref name="destination"/> is too small.</returns>
+    /// <exception cref="ArgumentNullException"><paramref name="name"/> is <c>null</c>.</exception>
+    /// <exception cref="ArgumentException"><paramref name="name"/> contains a null character.</exception>
+    public static bool TryGreet(string name, Span<byte> destination, out int bytesWritten)
+    {
+        return TryGreet(ToNullTerminated(name, nameof(name)), destination, out bytesWritten);
+    }
+
+    /// <summary>
+    ///     Writes a UTF-8 greeting for <paramref name="utf8Name"/>, a UTF-8 encoded name, into
+    ///     <paramref name="destination"/>, followed by a null terminator.
+    /// </summary>
+    /// <param name="utf8Name">The name to greet.</param>
+    /// <param name="destination">Buffer for the greeting. Needs one byte more than the greeting, for the terminator.</param>
+    /// <param name="bytesWritten">Length of the greeting in bytes, without the terminator. <c>0</c> on failure.</param>
+    /// <returns><c>false</c> if <paramref name="destination"/> is too small.</returns>
+    /// <exception cref="ArgumentException">
+    ///     <paramref name="utf8Name"/> contains a null byte, or is not valid UTF-8.
+    /// </exception>
+    public static bool TryGreet(ReadOnlySpan<byte> utf8Name, Span<byte> destination, out int bytesWritten)
+    {
+        return TryGreet(ToNullTerminated(utf8Name, nameof(utf8Name)), destination, out bytesWritten);
+    }
+
+    /// <summary>
+    ///     Sums all bytes in <paramref name="data"/>.
+    /// </summary>
+    public static ulong SumBytes(ReadOnlySpan<byte> data)
+    {
+        ulong sum;
+        fixed (byte* dataPtr = data)
+        {
+            NativeMethods.{{crate_name}}_sum_bytes(dataPtr, (nuint)data.Length, &sum).ThrowIfFailed();
+        }
+
+        return sum;
+    }
+
+    private static string Greet(byte[] name)
+    {
+        fixed (byte* namePtr = name)
+        {
+            // Null only if the name isn't valid UTF-8, or the library panicked.
+            var greeting = NativeMethods.{{crate_name}}_greet(namePtr);
+            if (greeting == null)
+                throw new ArgumentException(LastError.Message(), nameof(name));
+
+            try
+            {
+                return Marshal.PtrToStringUTF8((IntPtr)greeting);
+            }
+            finally
+            {
+                NativeMethods.{{crate_name}}_free_string(greeting);
+            }
+        }
+    }
+
+    private static bool TryGreet(byte[] name, Span<byte> destination, out int bytesWritten)
+    {
+        nuint required;
+        FfiStatus status;
+        fixed (byte* namePtr = name)
+        fixed (byte* destinationPtr = destination)
+        {
+            status = NativeMethods.{{crate_name}}_greet_into(namePtr, destinationPtr, (nuint)destination.Length, &required);
+        }
+
+        bytesWritten = 0;
+        if (status == FfiStatus.BufferTooSmall)
+            return false;
+
+        status.ThrowIfFailed();
+        bytesWritten = (int)required - 1;
+        return true;
+    }
+
+    private static byte[] ToNullTerminated(string value, string paramName)
+    {
+        if (value == null)
+            throw new ArgumentNullException(paramName);
+        if (value.Contains('\0'))
+            throw new ArgumentException("The string must not contain null characters.", paramName);
+
+        var bytes = new byte[Encoding.UTF8.GetByteCount(value) + 1];
+        Encoding.UTF8.GetBytes(value, 0, value.Length, bytes, 0);
+        return bytes;
+    }
+
+    private static byte[] ToNullTerminated(ReadOnlySpan<byte> value, string paramName)
+    {
+        if (value.IndexOf((byte)0) >= 0)
+            throw new ArgumentException("The string must not contain null bytes.", paramName);
+
+        var bytes = new byte[value.Length + 1];
+        value.CopyTo(bytes);
+        return bytes;
+    }
+}
@@ -0,0 +1,60 @@
+using System;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+using System.Text;
+using System.Threading;
+
+namespace {{crate_name}}.Net.Sys;
+
+/// <summary>
+///     Receives a log message from the native library.
+/// </summary>
+/// <param name="level">Severity of the message.</param>
+/// <param name="message">The message, formatted as <c>message key=value</c>.</param>
+public delegate void LogHandler(FfiLogLevel level, string message);
+
+/// <summary>
+///     Forwards the native library's log messages to a managed <see cref="LogHandler"/>.
+/// </summary>
+/// <remarks>
+///     The handler may be called from any thread, including threads created by the native library.
+/// </remarks>
+public static unsafe class NativeLog
+{
+    private static LogHandler _handler;
+
+    /// <summary>
+    ///     Sets the handler that receives the native library's log messages, replacing the previous one.
+    /// </summary>
+    /// <param name="handler">Called for every message, or <c>null</c> to stop logging.</param>
+    /// <exception cref="InvalidOperationException">
+    ///     The native library could not install its log callback, e.g. because another <c>tracing</c>
+    ///     subscriber is already installed in the process.
+    /// </exception>
+    public static void SetHandler(LogHandler handler)
+    {
+        Volatile.Write(ref _handler, handler);
+
+        // A static function pointer stays valid forever, unlike a marshalled delegate,
+        // so there is nothing to keep alive while native code holds on to it.
+        var callback = handler == null ? null : (delegate* unmanaged[Cdecl]<FfiLogLevel, byte*, nuint, void>)&OnLog;
+        if (NativeMethods.{{crate_name}}_set_log_callback(callback) != FfiStatus.Ok)
+        {
+            Volatile.Write(ref _handler, null);
+            throw new InvalidOperationException("Failed to set the native log callback: " + LastError.Message());
+        }
+    }
+
+    [UnmanagedCallersOnly(CallConvs = new[] { typeof(CallConvCdecl) })]
+    private static void OnLog(FfiLogLevel level, byte* message, nuint len)
+    {
+        try
+        {
+            Volatile.Read(ref _handler)?.Invoke(level, Encoding.UTF8.GetString(message, (int)len));
+        }
+        catch
+        {
+            // Exceptions can't unwind into native code, they would terminate the process.
+        }
+    }
+}
@@ -1,4 +1,5 @@
 using System;
+using System.Collections.Generic;
 using System.IO;
 using System.Reflection;
 using System.Runtime.InteropServices;
@@ -7,64 +8,112 @@ namespace {{crate_name}}.Net.Sys;
 
 public static unsafe partial class NativeMethods
 {
-    // https://docs.microsoft.com/en-us/dotnet/standard/native-interop/cross-platform
-    // Library path will search
-    // win => __DllName, __DllName.dll
-    // linux, osx => __DllName.so, __DllName.dylib
+    /// <summary>
+    ///     RIDs the package ships a native library for, in <c>runtimes/&lt;rid&gt;/native</c>.
+    /// </summary>
+    /// <remarks>Matches the <c>rust.yml</c> build matrix, and the renames in <c>.github/artifact-groups.yml</c>.</remarks>
+    internal static readonly IReadOnlyList<string> PackagedRuntimeIdentifiers = new[]
+    {
+        "linux-x64",
+{%- if xplat %}
+        "linux-x86",
+        "linux-arm64",
+        "linux-arm",
+{%- if build_c_libs %}
+        "linux-musl-x64",
+        "linux-musl-arm64",
+        "freebsd-x64",
+{%- endif %}
+        "win-x64",
+        "win-x86",
+        "osx-x64",
+        "osx-arm64",
+{%- endif %}
+    };
+
+    // https://learn.microsoft.com/en-us/dotnet/standard/native-interop/cross-platform
+    // https://learn.microsoft.com/en-us/dotnet/core/rid-catalog
+    // Searches `runtimes/<rid>/native` for each of `RuntimeIdentifierCandidates`, then next to the app.
+    // win => __DllName.dll
+    // linux, freebsd => lib__DllName.so
+    // osx => lib__DllName.dylib
     internal static IntPtr DllImportResolver(string libraryName, Assembly assembly, DllImportSearchPath? searchPath)
     {
-        if (libraryName == __DllName)
+        if (libraryName != __DllName)
+            return IntPtr.Zero;
+
+        var fileName = NativeLibraryFileName();
+        foreach (var rid in RuntimeIdentifierCandidates())
         {
-            var dllName = __DllName;
-            var path = "runtimes/";
-            var extension = "";
+            var path = Path.Combine(AppContext.BaseDirectory, "runtimes", rid, "native", fileName);
+            if (NativeLibrary.TryLoad(path, assembly, searchPath, out var handle))
+                return handle;
+        }
+
+        if (NativeLibrary.TryLoad(Path.Combine(AppContext.BaseDirectory, fileName), out var local))
+            return local;
+
+        // Let the runtime's default search report the error.
+        return IntPtr.Zero;
+    }
 
-            if (RuntimeInformation.IsOSPlatform(OSPlatform.Windows))
-            {
-                path += "win-";
-                extension = ".dll";
-            }
-            else if (RuntimeInformation.IsOSPlatform(OSPlatform.OSX))
-            {
-                path += "osx-";
-                extension = ".dylib";
-                dllName = "lib" + dllName;
-            }
-            else
-            {
-                path += "linux-";
-                extension = ".so";
-                dllName = "lib" + dllName;
-            }
+    /// <summary>
+    ///     RIDs to look for the native library under, most specific first, following the .NET RID graph.
+    /// </summary>
+    /// <remarks>e.g. <c>linux-musl-x64</c> then <c>linux-x64</c> on Alpine.</remarks>
+    internal static IEnumerable<string> RuntimeIdentifierCandidates()
+    {
+        var arch = RuntimeInformation.ProcessArchitecture.ToString().ToLowerInvariant();
+        var seen = new HashSet<string>();
 
-            if (RuntimeInformation.ProcessArchitecture == Architecture.X86)
-            {
-                path += "x86";
-            }
-            else if (RuntimeInformation.ProcessArchitecture == Architecture.X64)
-            {
-                path += "x64";
-            }
-            else if (RuntimeInformation.ProcessArchitecture == Architecture.Arm)
-            {
-                path += "arm";
-            }
-            else if (RuntimeInformation.ProcessArchitecture == Architecture.Arm64)
-            {
-                path += "arm64";
-            }
+        // Set by RID-specific builds, otherwise the portable RID, e.g. `linux-x64`.
+        if (seen.Add(RuntimeInformation.RuntimeIdentifier))
+            yield return RuntimeInformation.RuntimeIdentifier;
 
-            path += "/native/" + dllName + extension;
-            try
-            {
-                return NativeLibrary.Load(Path.Combine(AppContext.BaseDirectory, path), assembly, searchPath);
-            }
-            catch (DllNotFoundException)
-            {
-                return NativeLibrary.Load(Path.Combine(AppContext.BaseDirectory, dllName + extension));
-            }
+        if (OperatingSystem.IsWindows())
+        {
+            if (seen.Add($"win-{arch}"))
+                yield return $"win-{arch}";
+        }
+        else if (OperatingSystem.IsMacOS())
+        {
+            if (seen.Add($"osx-{arch}"))
+                yield return $"osx-{arch}";
         }
+        else if (OperatingSystem.IsFreeBSD())
+        {
+            if (seen.Add($"freebsd-{arch}"))
+                yield return $"freebsd-{arch}";
+        }
+        else
+        {
+            // `linux-musl-<arch>` falls back to `linux-<arch>` in the RID graph.
+            if (IsMusl() && seen.Add($"linux-musl-{arch}"))
+                yield return $"linux-musl-{arch}";
+            if (seen.Add($"linux-{arch}"))
+                yield return $"linux-{arch}";
+        }
+    }
 
-        return IntPtr.Zero;
+    /// <summary>
+    ///     <c>true</c> if the process runs on musl libc, such as on Alpine Linux.
+    /// </summary>
+    internal static bool IsMusl()
+    {
+        if (!OperatingSystem.IsLinux())
+            return false;
+
+        // RID-specific builds know, e.g. `linux-musl-x64`. Portable builds check for musl's dynamic loader.
+        return RuntimeInformation.RuntimeIdentifier.Contains("musl", StringComparison.Ordinal)
+            || (Directory.Exists("/lib") && Directory.GetFiles("/lib", "ld-musl-*.so.1").Length > 0);
+    }
+
+    private static string NativeLibraryFileName()
+    {
+        if (OperatingSystem.IsWindows())
+            return __DllName + ".dll";
+        if (OperatingSystem.IsMacOS())
+            return "lib" + __DllName + ".dylib";
+        return "lib" + __DllName + ".so";
     }
 }
@@ -21,9 +21,302 @@ namespace {{crate_name}}.Net.Sys
         [DllImport(__DllName, EntryPoint = "it_works", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
         public static extern int it_works();
 
+        /// <summary>
+        ///  Adds two numbers together, failing if the result overflows.
+        ///
+        ///  # Parameters
+        ///
+        ///  - `left`: The first number.
+        ///  - `right`: The second number.
+        ///  - `out_result`: Receives the sum on success.
+        ///
+        ///  # Returns
+        ///
+        ///  - [`FfiStatus::Ok`] on success.
+        ///  - [`FfiStatus::Error`] if the sum overflows.
+        ///  - [`FfiStatus::NullPointer`] if `out_result` is null.
+        ///  - [`FfiStatus::Panic`] if the library panicked.
+        ///
+        ///  # Safety
+        ///
+        ///  `out_result` must be valid for writes.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "{{crate_name}}_checked_add", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern FfiStatus {{crate_name}}_checked_add(ulong left, ulong right, ulong* out_result);
 
+        /// <summary>
+        ///  Creates a new counter starting at zero.
+        ///
+        ///  # Returns
+        ///
+        ///  An owning pointer to the counter, or null if creation failed.
+        ///  The counter must be released with [`{{crate_name}}_counter_free`].
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "{{crate_name}}_counter_new", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Counter* {{crate_name}}_counter_new();
+
+        /// <summary>
+        ///  Releases a counter created by [`{{crate_name}}_counter_new`].
+        ///
+        ///  # Returns
+        ///
+        ///  - [`FfiStatus::Ok`] if the counter was released.
+        ///  - [`FfiStatus::NullPointer`] if `counter` is null.
+        ///  - [`FfiStatus::InvalidHandle`] if `counter` was already released (debug builds only).
+        ///
+        ///  # Safety
+        ///
+        ///  `counter` must be null or a pointer returned by [`{{crate_name}}_counter_new`].
+        ///  It must not be used after this call.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "{{crate_name}}_counter_free", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern FfiStatus {{crate_name}}_counter_free(Counter* counter);
+
+        /// <summary>
+        ///  Increments the counter.
+        ///
+        ///  # Parameters
+        ///
+        ///  - `counter`: The counter to increment.
+        ///  - `out_value`: Receives the new value on success.
+        ///
+        ///  # Safety
+        ///
+        ///  `counter` must be a live pointer returned by [`{{crate_name}}_counter_new`].
+        ///  `out_value` must be valid for writes.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "{{crate_name}}_counter_increment", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern FfiStatus {{crate_name}}_counter_increment(Counter* counter, ulong* out_value);
+
+        /// <summary>
+        ///  Gets the current value of the counter.
+        ///
+        ///  # Parameters
+        ///
+        ///  - `counter`: The counter to read.
+        ///  - `out_value`: Receives the current value on success.
+        ///
+        ///  # Safety
+        ///
+        ///  `counter` must be a live pointer returned by [`{{crate_name}}_counter_new`].
+        ///  `out_value` must be valid for writes.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "{{crate_name}}_counter_value", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern FfiStatus {{crate_name}}_counter_value(Counter* counter, ulong* out_value);
+
+        /// <summary>
+        ///  Returns the length of the last error message in bytes, including the null terminator.
+        ///
+        ///  # Returns
+        ///
+        ///  `0` if no error has been recorded on the current thread.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "{{crate_name}}_last_error_length", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern nuint {{crate_name}}_last_error_length();
+
+        /// <summary>
+        ///  Copies the last error message, including the null terminator, into `buffer`.
+        ///
+        ///  # Parameters
+        ///
+        ///  - `buffer`: Buffer to write the message to.
+        ///  - `buffer_len`: Length of `buffer` in bytes. Use [`{{crate_name}}_last_error_length`] to size it.
+        ///
+        ///  # Returns
+        ///
+        ///  - [`FfiStatus::Ok`] if the message was copied. An empty string is written if there is no error.
+        ///  - [`FfiStatus::NullPointer`] if `buffer` is null.
+        ///  - [`FfiStatus::BufferTooSmall`] if `buffer_len` is smaller than the message length.
+        ///
+        ///  # Safety
+        ///
+        ///  `buffer` must be valid for writes of `buffer_len` bytes.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "{{crate_name}}_last_error_message", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern FfiStatus {{crate_name}}_last_error_message(byte* buffer, nuint buffer_len);
+
+        /// <summary>
+        ///  Releases a string returned by this library.
+        ///
+        ///  # Safety
+        ///
+        ///  `value` must be null or a string returned by this library that has not been freed yet.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "{{crate_name}}_free_string", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern void {{crate_name}}_free_string(byte* value);
+
+        /// <summary>
+        ///  Creates a greeting for `name`.
+        ///
+        ///  # Returns
+        ///
+        ///  A string that must be released with [`{{crate_name}}_free_string`],
+        ///  or null if `name` is null or not valid UTF-8.
+        ///
+        ///  # Safety
+        ///
+        ///  `name` must be null or a null-terminated string.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "{{crate_name}}_greet", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern byte* {{crate_name}}_greet(byte* name);
+
+        /// <summary>
+        ///  Writes a greeting for `name` into a caller-provided buffer.
+        ///
+        ///  # Parameters
+        ///
+        ///  - `name`: Null-terminated UTF-8 name.
+        ///  - `buffer`: Buffer to write the greeting to. May be null if `buffer_len` is zero.
+        ///  - `buffer_len`: Length of `buffer` in bytes.
+        ///  - `out_required`: Receives the length needed, including the null terminator. May be null.
+        ///
+        ///  # Returns
+        ///
+        ///  - [`FfiStatus::Ok`] if the greeting was written.
+        ///  - [`FfiStatus::BufferTooSmall`] if `buffer_len` is smaller than the required length.
+        ///  - [`FfiStatus::NullPointer`] if `name` is null.
+        ///  - [`FfiStatus::InvalidUtf8`] if `name` is not valid UTF-8.
+        ///
+        ///  # Safety
+        ///
+        ///  `name` must be null or a null-terminated string.
+        ///  `buffer` must be valid for writes of `buffer_len` bytes.
+        ///  `out_required` must be null or valid for writes.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "{{crate_name}}_greet_into", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern FfiStatus {{crate_name}}_greet_into(byte* name, byte* buffer, nuint buffer_len, nuint* out_required);
+
+        /// <summary>
+        ///  Sums all bytes in `data`.
+        ///
+        ///  # Parameters
+        ///
+        ///  - `data`: Bytes to sum. May be null if `data_len` is zero.
+        ///  - `data_len`: Number of bytes in `data`.
+        ///  - `out_sum`: Receives the sum on success.
+        ///
+        ///  # Safety
+        ///
+        ///  `data` must be valid for reads of `data_len` bytes. `out_sum` must be valid for writes.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "{{crate_name}}_sum_bytes", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern FfiStatus {{crate_name}}_sum_bytes(byte* data, nuint data_len, ulong* out_sum);
+{%- if tracing %}
+
+        /// <summary>
+        ///  Sets the callback that receives the library's log messages, replacing the previous one.
+        ///
+        ///  # Parameters
+        ///
+        ///  - `callback`: Function called for every message, or null to stop logging.
+        ///
+        ///  # Returns
+        ///
+        ///  - [`FfiStatus::Ok`] if the callback was set.
+        ///  - [`FfiStatus::Error`] if another `tracing` subscriber is already installed.
+        ///  - [`FfiStatus::Panic`] if the library panicked.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "{{crate_name}}_set_log_callback", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern FfiStatus {{crate_name}}_set_log_callback(delegate* unmanaged[Cdecl]<FfiLogLevel, byte*, nuint, void> callback);
+{%- endif %}
+
+        /// <summary>
+        ///  Returns the version of the library, as set in `Cargo.toml`.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "{{crate_name}}_version", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Version {{crate_name}}_version();
+
+
+    }
+
+    /// <summary>
+    ///  Version of the library, so callers can check that they loaded a compatible build.
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    public unsafe partial struct Version
+    {
+        /// <summary>
+        ///  Major version. Changes when the C API breaks.
+        /// </summary>
+        public uint major;
+        /// <summary>
+        ///  Minor version.
+        /// </summary>
+        public uint minor;
+        /// <summary>
+        ///  Patch version.
+        /// </summary>
+        public uint patch;
+        /// <summary>
+        ///  `true` for pre-release versions such as `1.0.0-beta.1`.
+        /// </summary>
+        [MarshalAs(UnmanagedType.U1)] public bool pre_release;
     }
 
 
+    /// <summary>
+    ///  Status code returned by fallible exports.
+    /// </summary>
+    public enum FfiStatus : uint
+    {
+        /// <summary>
+        ///  The operation completed successfully.
+        /// </summary>
+        Ok = 0,
+        /// <summary>
+        ///  The operation failed. Check the last error message for details.
+        /// </summary>
+        Error = 1,
+        /// <summary>
+        ///  A required pointer argument was null.
+        /// </summary>
+        NullPointer = 2,
+        /// <summary>
+        ///  The provided buffer was too small to hold the result.
+        /// </summary>
+        BufferTooSmall = 3,
+        /// <summary>
+        ///  The library panicked. Check the last error message for details.
+        /// </summary>
+        Panic = 4,
+        /// <summary>
+        ///  A handle was invalid, e.g. it was already freed.
+        /// </summary>
+        InvalidHandle = 5,
+        /// <summary>
+        ///  A string argument was not valid UTF-8.
+        /// </summary>
+        InvalidUtf8 = 6,
+    }
+{%- if tracing %}
+
+    /// <summary>
+    ///  Severity of a log message, from most to least severe.
+    /// </summary>
+    public enum FfiLogLevel : uint
+    {
+        /// <summary>
+        ///  Something failed.
+        /// </summary>
+        Error = 1,
+        /// <summary>
+        ///  Something unexpected happened, but the library recovered.
+        /// </summary>
+        Warn = 2,
+        /// <summary>
+        ///  High level progress.
+        /// </summary>
+        Info = 3,
+        /// <summary>
+        ///  Details useful when debugging.
+        /// </summary>
+        Debug = 4,
+        /// <summary>
+        ///  Very verbose details, such as function return values.
+        /// </summary>
+        Trace = 5,
+    }
+{%- endif %}
+
 
 }
@@ -0,0 +1,46 @@
+using System;
+using System.Threading.Tasks;
+using Xunit;
+
+namespace {{crate_name}}.Net.Tests;
+
+public class CounterTests
+{
+    [Fact]
+    public void Increment_ReturnsNewValue()
+    {
+        using var counter = new Counter();
+        Assert.Equal(0ul, counter.Value);
+        Assert.Equal(1ul, counter.Increment());
+        Assert.Equal(2ul, counter.Increment());
+        Assert.Equal(2ul, counter.Value);
+    }
+
+    [Fact]
+    public void Counters_AreIndependent()
+    {
+        using var first = new Counter();
+        using var second = new Counter();
+        first.Increment();
+        Assert.Equal(0ul, second.Value);
+    }
+
+    [Fact]
+    public void Dispose_ThrowsOnLaterCalls()
+    {
+        var counter = new Counter();
+        counter.Dispose();
+        counter.Dispose();
+
+        Assert.Throws<ObjectDisposedException>(() => counter.Increment());
+        Assert.Throws<ObjectDisposedException>(() => counter.Value);
+    }
+
+    [Fact]
+    public void Increment_IsThreadSafe()
+    {
+        using var counter = new Counter();
+        Parallel.For(0, 1000, _ => counter.Increment());
+        Assert.Equal(1000ul, counter.Value);
+    }
+}
@@ -0,0 +1,85 @@
+using System;
+using System.Text;
+using Xunit;
+
+namespace {{crate_name}}.Net.Sys.Tests;
+
+public unsafe class FfiTests
+{
+    [Fact]
+    public void Greet_RoundTripsString()
+    {
+        fixed (byte* name = "World\0"u8)
+        {
+            var greeting = NativeMethods.{{crate_name}}_greet(name);
+            Assert.True(greeting != null);
+            try
+            {
+                Assert.Equal("Hello, World!", new string((sbyte*)greeting));
+            }
+            finally
+            {
+                NativeMethods.{{crate_name}}_free_string(greeting);
+            }
+        }
+    }
+
+    [Fact]
+    public void Greet_ReturnsNullForInvalidUtf8()
+    {
+        fixed (byte* name = new byte[] { 0xFF, 0 })
+        {
+            Assert.True(NativeMethods.{{crate_name}}_greet(name) == null);
+            Assert.NotEqual(0u, (uint)NativeMethods.{{crate_name}}_last_error_length());
+        }
+    }
+
+    [Fact]
+    public void GreetInto_ReportsRequiredLength()
+    {
+        fixed (byte* name = "World\0"u8)
+        {
+            nuint required = 0;
+            var status = NativeMethods.{{crate_name}}_greet_into(name, null, 0, &required);
+            Assert.Equal(FfiStatus.BufferTooSmall, status);
+            Assert.Equal((nuint)"Hello, World!\0".Length, required);
+
+            var buffer = new byte[(int)required];
+            fixed (byte* bufferPtr = buffer)
+            {
+                status = NativeMethods.{{crate_name}}_greet_into(name, bufferPtr, (nuint)buffer.Length, &required);
+                Assert.Equal(FfiStatus.Ok, status);
+            }
+
+            Assert.Equal("Hello, World!", Encoding.UTF8.GetString(buffer, 0, buffer.Length - 1));
+        }
+    }
+
+    [Fact]
+    public void SumBytes_SumsSpan()
+    {
+        ReadOnlySpan<byte> data = stackalloc byte[] { 1, 2, 3 };
+        ulong sum = 0;
+        fixed (byte* dataPtr = data)
+        {
+            var status = NativeMethods.{{crate_name}}_sum_bytes(dataPtr, (nuint)data.Length, &sum);
+            Assert.Equal(FfiStatus.Ok, status);
+        }
+
+        Assert.Equal(6ul, sum);
+    }
+
+    [Fact]
+    public void SumBytes_AcceptsEmptyInput()
+    {
+        ulong sum = 1;
+        Assert.Equal(FfiStatus.Ok, NativeMethods.{{crate_name}}_sum_bytes(null, 0, &sum));
+        Assert.Equal(0ul, sum);
+    }
+
+    [Fact]
+    public void FreeString_AcceptsNull()
+    {
+        NativeMethods.{{crate_name}}_free_string(null);
+    }
+}
@@ -0,0 +1,75 @@
+using System;
+using System.Text;
+using Xunit;
+
+namespace {{crate_name}}.Net.Tests;
+
+public class LibraryTests
+{
+    [Fact]
+    public void Version_MatchesCrate()
+    {
+        Assert.Equal(new Version(0, 1, 0), Library.Version);
+        Assert.False(Library.IsPreRelease);
+    }
+
+    [Fact]
+    public void CheckedAdd_AddsNumbers()
+    {
+        Assert.Equal(4ul, Library.CheckedAdd(2, 2));
+    }
+
+    [Fact]
+    public void CheckedAdd_ThrowsOnOverflow()
+    {
+        var exception = Assert.Throws<OverflowException>(() => Library.CheckedAdd(ulong.MaxValue, 1));
+        Assert.Equal("integer overflow", exception.Message);
+    }
+
+    [Fact]
+    public void Greet_RoundTripsString()
+    {
+        Assert.Equal("Hello, World!", Library.Greet("World"));
+        Assert.Equal("Hello, Wörld!", Library.Greet("Wörld"));
+    }
+
+    [Fact]
+    public void Greet_AcceptsUtf8Span()
+    {
+        Assert.Equal("Hello, World!", Library.Greet("World"u8));
+    }
+
+    [Fact]
+    public void Greet_RejectsInvalidInput()
+    {
+        Assert.Throws<ArgumentNullException>(() => Library.Greet((string)null));
+        Assert.Throws<ArgumentException>(() => Library.Greet("Wor\0ld"));
+        Assert.Throws<ArgumentException>(() => Library.Greet(new byte[] { 0xFF }));
+    }
+
+    [Fact]
+    public void TryGreet_WritesIntoSpan()
+    {
+        Span<byte> buffer = stackalloc byte[32];
+        Assert.True(Library.TryGreet("World", buffer, out var bytesWritten));
+        Assert.Equal("Hello, World!", Encoding.UTF8.GetString(buffer[..bytesWritten]));
+        Assert.Equal(0, buffer[bytesWritten]);
+    }
+
+    [Fact]
+    public void TryGreet_ReturnsFalseWhenTooSmall()
+    {
+        // Fits the greeting, but not the null terminator.
+        Span<byte> buffer = stackalloc byte["Hello, World!".Length];
+        Assert.False(Library.TryGreet("World"u8, buffer, out var bytesWritten));
+        Assert.Equal(0, bytesWritten);
+        Assert.False(Library.TryGreet("World", Span<byte>.Empty, out _));
+    }
+
+    [Fact]
+    public void SumBytes_SumsSpan()
+    {
+        Assert.Equal(258ul, Library.SumBytes(new byte[] { 1, 2, 255 }));
+        Assert.Equal(0ul, Library.SumBytes(ReadOnlySpan<byte>.Empty));
+    }
+}
@@ -0,0 +1,66 @@
+using System;
+using System.Collections.Concurrent;
+using Xunit;
+
+namespace {{crate_name}}.Net.Sys.Tests;
+
+// The log handler is process-wide, so counters in other test classes would log into these tests.
+// The collection runs on its own, after the parallel tests.
+[CollectionDefinition(nameof(NativeLogTests), DisableParallelization = true)]
+public class NativeLogCollection
+{
+}
+
+[Collection(nameof(NativeLogTests))]
+public unsafe class NativeLogTests
+{
+    [Fact]
+    public void SetHandler_ReceivesNativeMessages()
+    {
+        var messages = new ConcurrentQueue<(FfiLogLevel, string)>();
+        NativeLog.SetHandler((level, message) => messages.Enqueue((level, message)));
+        try
+        {
+            // `Counter::increment` logs its return value at the trace level.
+            IncrementNewCounter();
+        }
+        finally
+        {
+            NativeLog.SetHandler(null);
+        }
+
+        Assert.Contains((FfiLogLevel.Trace, "return=1"), messages);
+    }
+
+    [Fact]
+    public void SetHandler_NullStopsMessages()
+    {
+        var count = 0;
+        NativeLog.SetHandler((_, _) => count++);
+        NativeLog.SetHandler(null);
+
+        IncrementNewCounter();
+        Assert.Equal(0, count);
+    }
+
+    [Fact]
+    public void SetHandler_SwallowsHandlerExceptions()
+    {
+        NativeLog.SetHandler((_, _) => throw new InvalidOperationException("handler failed"));
+        try
+        {
+            IncrementNewCounter();
+        }
+        finally
+        {
+            NativeLog.SetHandler(null);
+        }
+    }
+
+    private static void IncrementNewCounter()
+    {
+        using var counter = CounterHandle.Create();
+        ulong value;
+        Assert.Equal(FfiStatus.Ok, NativeMethods.{{crate_name}}_counter_increment(counter.Pointer, &value));
+    }
+}
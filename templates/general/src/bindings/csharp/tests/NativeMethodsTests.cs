@@ -0,0 +1,54 @@
+using System;
+using System.Linq;
+using System.Runtime.InteropServices;
+using Xunit;
+
+namespace {{crate_name}}.Net.Sys.Tests;
+
+public class NativeMethodsTests
+{
+    [Fact]
+    public void RuntimeIdentifierCandidates_StartWithCurrentRid()
+    {
+        var candidates = NativeMethods.RuntimeIdentifierCandidates().ToList();
+        Assert.Equal(RuntimeInformation.RuntimeIdentifier, candidates[0]);
+        Assert.Equal(candidates.Count, candidates.Distinct().Count());
+    }
+
+    [Fact]
+    public void RuntimeIdentifierCandidates_EndWithPortableRid()
+    {
+        var arch = RuntimeInformation.ProcessArchitecture.ToString().ToLowerInvariant();
+        var os = OperatingSystem.IsWindows() ? "win"
+            : OperatingSystem.IsMacOS() ? "osx"
+            : OperatingSystem.IsFreeBSD() ? "freebsd"
+            : "linux";
+
+        Assert.Equal($"{os}-{arch}", NativeMethods.RuntimeIdentifierCandidates().Last());
+    }
+
+    [Fact]
+    public void RuntimeIdentifierCandidates_PreferMuslOnMusl()
+    {
+        var candidates = NativeMethods.RuntimeIdentifierCandidates().ToList();
+        var arch = RuntimeInformation.ProcessArchitecture.ToString().ToLowerInvariant();
+        if (NativeMethods.IsMusl())
+            Assert.True(candidates.IndexOf($"linux-musl-{arch}") < candidates.IndexOf($"linux-{arch}"));
+        else
+            Assert.DoesNotContain($"linux-musl-{arch}", candidates);
+    }
+
+    [Fact]
+    public void PackagedRuntimeIdentifiers_AreUniqueAndIncludeLinuxX64()
+    {
+        // CI runs the tests on linux-x64, which every build packages.
+        Assert.Contains("linux-x64", NativeMethods.PackagedRuntimeIdentifiers);
+        Assert.Equal(NativeMethods.PackagedRuntimeIdentifiers.Count, NativeMethods.PackagedRuntimeIdentifiers.Distinct().Count());
+    }
+
+    [Fact]
+    public void DllImportResolver_IgnoresOtherLibraries()
+    {
+        Assert.Equal(IntPtr.Zero, NativeMethods.DllImportResolver("not-{{crate_name}}", typeof(NativeMethods).Assembly, null));
+    }
+}
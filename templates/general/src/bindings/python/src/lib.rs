@@ -0,0 +1,75 @@
+//! Python bindings for {{project-name}}, made with PyO3.
+//!
+//! They wrap the Rust API, so overflows raise `OverflowError` instead of panicking, and
+//! [`Counter`](python::PyCounter) becomes a Python class. Types are in `{{crate_name}}.pyi`.
+//!
+//! The wheel is built with `maturin`, and tested by the pytest suite in `tests`:
+//!
+//! ```bash
+//! python -m venv .venv && source .venv/bin/activate
+//! pip install maturin pytest
+//! maturin develop
+//! pytest
+//! ```
+
+use pyo3::prelude::*;
+
+/// Python bindings for the {{project-name}} Rust library.
+#[pymodule(name = "{{crate_name}}")]
+mod python {
+    use pyo3::exceptions::PyOverflowError;
+    use pyo3::prelude::*;
+
+    /// Adds two numbers together.
+    ///
+    /// Raises `OverflowError` if the sum doesn't fit in 64 bits.
+    #[pyfunction]
+    fn add(left: u64, right: u64) -> PyResult<u64> {
+        {{crate_name}}::checked_add(left, right)
+            .ok_or_else(|| PyOverflowError::new_err("integer overflow"))
+    }
+
+    /// Adds `value` to every number in `values`, returning the sums in a new list.
+    ///
+    /// Raises `OverflowError` if any sum doesn't fit in 64 bits.
+    #[pyfunction]
+    fn add_to_all(values: Vec<u64>, value: u64) -> PyResult<Vec<u64>> {
+        values
+            .iter()
+            .map(|&left| {
+                {{crate_name}}::checked_add(left, value)
+                    .ok_or_else(|| PyOverflowError::new_err("integer overflow"))
+            })
+            .collect()
+    }
+
+    /// A simple counter, used as an example of a stateful object.
+    #[pyclass(name = "Counter")]
+    #[derive(Debug, Default)]
+    pub struct PyCounter({{crate_name}}::Counter);
+
+    #[pymethods]
+    impl PyCounter {
+        /// Creates a new counter starting at zero.
+        #[new]
+        fn new() -> Self {
+            Self::default()
+        }
+
+        /// Increments the counter, returning the new value.
+        fn increment(&mut self) -> u64 {
+            self.0.increment()
+        }
+
+        /// The current value of the counter.
+        #[getter]
+        fn value(&self) -> u64 {
+            self.0.value()
+        }
+    }
+
+    #[pymodule_init]
+    fn init(module: &Bound<'_, PyModule>) -> PyResult<()> {
+        module.add("__version__", env!("CARGO_PKG_VERSION"))
+    }
+}
@@ -0,0 +1,50 @@
+"""Round-trip tests for the Python bindings. Run `maturin develop` first, see README.md."""
+
+import pytest
+
+import {{crate_name}}
+
+U64_MAX = 2**64 - 1
+
+
+def test_add():
+    assert {{crate_name}}.add(2, 2) == 4
+
+
+def test_add_raises_on_overflow():
+    with pytest.raises(OverflowError):
+        {{crate_name}}.add(U64_MAX, 1)
+
+
+def test_add_rejects_negative_numbers():
+    with pytest.raises(OverflowError):
+        {{crate_name}}.add(-1, 1)
+
+
+def test_add_to_all_adds_to_each_value():
+    assert {{crate_name}}.add_to_all([1, 2, 3], 10) == [11, 12, 13]
+    assert {{crate_name}}.add_to_all([], 10) == []
+
+
+def test_add_to_all_raises_on_overflow():
+    with pytest.raises(OverflowError):
+        {{crate_name}}.add_to_all([1, U64_MAX], 1)
+
+
+def test_counter_increments():
+    counter = {{crate_name}}.Counter()
+    assert counter.value == 0
+    assert counter.increment() == 1
+    assert counter.increment() == 2
+    assert counter.value == 2
+
+
+def test_counters_are_independent():
+    first = {{crate_name}}.Counter()
+    second = {{crate_name}}.Counter()
+    first.increment()
+    assert second.value == 0
+
+
+def test_version_is_set():
+    assert {{crate_name}}.__version__ == "0.1.0"
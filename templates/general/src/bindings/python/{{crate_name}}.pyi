@@ -0,0 +1,28 @@
+"""Python bindings for the {{project-name}} Rust library."""
+
+__version__: str
+
+def add(left: int, right: int) -> int:
+    """Adds two numbers together.
+
+    Raises `OverflowError` if the sum doesn't fit in 64 bits.
+    """
+
+def add_to_all(values: list[int], value: int) -> list[int]:
+    """Adds `value` to every number in `values`, returning the sums in a new list.
+
+    Raises `OverflowError` if any sum doesn't fit in 64 bits.
+    """
+
+class Counter:
+    """A simple counter, used as an example of a stateful object."""
+
+    def __init__(self) -> None:
+        """Creates a new counter starting at zero."""
+
+    def increment(self) -> int:
+        """Increments the counter, returning the new value."""
+
+    @property
+    def value(self) -> int:
+        """The current value of the counter."""
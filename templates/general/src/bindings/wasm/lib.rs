@@ -0,0 +1,5 @@
+//! npm package for {{project-name}}, built with `wasm-pack build`.
+//!
+//! The exports are in the library's `wasm` module, this crate only links them into a `cdylib`.
+
+pub use {{crate_name}}::wasm::*;
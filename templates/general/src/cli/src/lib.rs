@@ -0,0 +1,72 @@
+//! HTTP service for {{project-name}}.
+//!
+//! The binary in `main.rs` loads the [`config`], then runs [`serve`] until [`shutdown_signal`]
+//! completes. Tests can call [`serve`] with their own listener and shutdown future.
+
+pub mod config;
+
+use axum::extract::Path;
+use axum::http::StatusCode;
+use axum::routing::get;
+use axum::Router;
+use std::future::Future;
+use std::io;
+use tokio::net::TcpListener;
+
+/// Creates the routes served by the service.
+pub fn router() -> Router {
+    Router::new()
+        .route("/health", get(health))
+        .route("/add/{left}/{right}", get(add))
+}
+
+/// Serves [`router`] on `listener` until `shutdown` completes.
+///
+/// Requests that are already running are finished before returning.
+pub async fn serve<F>(listener: TcpListener, shutdown: F) -> io::Result<()>
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    axum::serve(listener, router())
+        .with_graceful_shutdown(shutdown)
+        .await
+}
+
+/// Completes when the process receives Ctrl+C (SIGINT), or SIGTERM on Unix.
+pub async fn shutdown_signal() {
+    let ctrl_c = async {
+        tokio::signal::ctrl_c()
+            .await
+            .expect("failed to listen for Ctrl+C");
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        use tokio::signal::unix::{signal, SignalKind};
+        signal(SignalKind::terminate())
+            .expect("failed to listen for SIGTERM")
+            .recv()
+            .await;
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        () = ctrl_c => {}
+        () = terminate => {}
+    }
+}
+
+/// Reports that the service is running.
+async fn health() -> &'static str {
+    "ok"
+}
+
+/// Adds two numbers together using the library.
+///
+/// Responds with `400 Bad Request` if the sum doesn't fit in 64 bits.
+async fn add(Path((left, right)): Path<(u64, u64)>) -> Result<String, (StatusCode, &'static str)> {
+    {{crate_name}}::checked_add(left, right)
+        .map(|sum| sum.to_string())
+        .ok_or((StatusCode::BAD_REQUEST, "integer overflow"))
+}
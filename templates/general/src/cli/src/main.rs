@@ -1 +1,262 @@
-fn main() {}
+{%- if service -%}
+//! HTTP service for {{project-name}}.
+//!
+//! # Getting Started
+//! Add new routes in [`{{crate_name}}_cli::router`], and new settings to [`Config`].
+//!
+//! # Running
+//! ```bash
+//! cargo run -p {{project-name}}-cli -- --config cli/config.toml
+//! ```
+
+use clap::Parser;
+use std::error::Error;
+{%- if tracing %}
+use std::io;
+{%- endif %}
+use std::path::PathBuf;
+use std::process::ExitCode;
+use tokio::net::TcpListener;
+{%- if tracing %}
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::EnvFilter;
+{%- endif %}
+
+use {{crate_name}}_cli::config::Config;
+use {{crate_name}}_cli::{serve, shutdown_signal};
+
+/// {{project_description}}
+#[derive(Debug, Parser)]
+#[command(version, about, long_about = None)]
+struct Cli {
+    /// Path to a TOML config file. Uses the default settings if omitted.
+    #[arg(long)]
+    config: Option<PathBuf>,
+{%- if tracing %}
+    /// Logs debug messages. `RUST_LOG` takes precedence.
+    #[arg(short, long, global = true, conflicts_with = "quiet")]
+    verbose: bool,
+    /// Only logs errors. `RUST_LOG` takes precedence.
+    #[arg(short, long, global = true)]
+    quiet: bool,
+{%- endif %}
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+{%- if tracing %}
+    init_tracing(&cli);
+{%- endif %}
+    match run(cli).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Serves requests until the process receives Ctrl+C or SIGTERM.
+///
+/// Errors are reported by [`main`] and turned into a non-zero exit code.
+async fn run(cli: Cli) -> Result<(), Box<dyn Error>> {
+    let config = Config::load(cli.config.as_deref())?;
+    let listener = TcpListener::bind(config.address).await?;
+    let address = listener.local_addr()?;
+{%- if tracing %}
+    tracing::info!("listening on http://{address}");
+{%- else %}
+    println!("listening on http://{address}");
+{%- endif %}
+
+    serve(listener, shutdown_signal()).await?;
+{%- if tracing %}
+    tracing::info!("shut down");
+{%- else %}
+    println!("shut down");
+{%- endif %}
+    Ok(())
+}
+{%- if tracing %}
+
+/// Logs to stderr, at the level chosen with `--verbose` or `--quiet`.
+///
+/// `RUST_LOG` takes precedence, e.g. `RUST_LOG={{crate_name}}=trace`.
+fn init_tracing(cli: &Cli) {
+    let level = if cli.verbose {
+        LevelFilter::DEBUG
+    } else if cli.quiet {
+        LevelFilter::ERROR
+    } else {
+        LevelFilter::INFO
+    };
+    let filter = EnvFilter::builder()
+        .with_default_directive(level.into())
+        .from_env_lossy();
+
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(io::stderr)
+        .init();
+}
+{%- endif %}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn verify_cli() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn parses_config() {
+        let args = ["{{project-name}}-cli", "--config", "config.toml"];
+        let cli = Cli::try_parse_from(args).unwrap();
+        assert_eq!(cli.config, Some(PathBuf::from("config.toml")));
+    }
+{%- if tracing %}
+
+    #[test]
+    fn verbose_conflicts_with_quiet() {
+        let args = ["{{project-name}}-cli", "--verbose", "--quiet"];
+        assert!(Cli::try_parse_from(args).is_err());
+    }
+{%- endif %}
+}
+{%- else -%}
+//! Command-line interface for {{project-name}}.
+//!
+//! # Getting Started
+//! Add new subcommands to [`Command`], then handle them in [`run`].
+//!
+//! # Running
+//! ```bash
+//! cargo run -p {{project-name}}-cli -- --help
+//! ```
+
+use clap::{Parser, Subcommand};
+use std::error::Error;
+use std::io::{self, Write};
+use std::process::ExitCode;
+{%- if tracing %}
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::EnvFilter;
+{%- endif %}
+
+/// {{project_description}}
+#[derive(Debug, Parser)]
+#[command(version, about, long_about = None)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+{%- if tracing %}
+    /// Logs debug messages. `RUST_LOG` takes precedence.
+    #[arg(short, long, global = true, conflicts_with = "quiet")]
+    verbose: bool,
+    /// Only logs errors. `RUST_LOG` takes precedence.
+    #[arg(short, long, global = true)]
+    quiet: bool,
+{%- endif %}
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Adds two numbers together using the library.
+    ///
+    /// Fails if the sum doesn't fit in 64 bits.
+    Add {
+        /// The first number.
+        left: u64,
+        /// The second number.
+        right: u64,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+{%- if tracing %}
+    init_tracing(&cli);
+{%- endif %}
+    match run(cli) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Executes the parsed command.
+///
+/// Errors are reported by [`main`] and turned into a non-zero exit code.
+fn run(cli: Cli) -> Result<(), Box<dyn Error>> {
+    let mut stdout = io::stdout().lock();
+    match cli.command {
+        Command::Add { left, right } => {
+            let sum = {{crate_name}}::checked_add(left, right).ok_or("integer overflow")?;
+            writeln!(stdout, "{sum}")?;
+        }
+    }
+
+    Ok(())
+}
+{%- if tracing %}
+
+/// Logs to stderr, at the level chosen with `--verbose` or `--quiet`.
+///
+/// `RUST_LOG` takes precedence, e.g. `RUST_LOG={{crate_name}}=trace`.
+fn init_tracing(cli: &Cli) {
+    let level = if cli.verbose {
+        LevelFilter::DEBUG
+    } else if cli.quiet {
+        LevelFilter::ERROR
+    } else {
+        LevelFilter::INFO
+    };
+    let filter = EnvFilter::builder()
+        .with_default_directive(level.into())
+        .from_env_lossy();
+
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(io::stderr)
+        .init();
+}
+{%- endif %}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn verify_cli() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn parses_add() {
+        let cli = Cli::try_parse_from(["{{project-name}}-cli", "add", "2", "2"]).unwrap();
+        assert!(matches!(cli.command, Command::Add { left: 2, right: 2 }));
+    }
+
+    #[test]
+    fn add_rejects_overflow() {
+        let max = u64::MAX.to_string();
+        let cli = Cli::try_parse_from(["{{project-name}}-cli", "add", &max, "1"]).unwrap();
+        assert!(run(cli).is_err());
+    }
+{%- if tracing %}
+
+    #[test]
+    fn verbose_conflicts_with_quiet() {
+        let args = ["{{project-name}}-cli", "-v", "-q", "add", "2", "2"];
+        assert!(Cli::try_parse_from(args).is_err());
+    }
+{%- endif %}
+}
+{%- endif %}
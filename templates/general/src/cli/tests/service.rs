@@ -0,0 +1,41 @@
+//! Starts the service on a free localhost port and sends it HTTP requests.
+
+use std::net::SocketAddr;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::oneshot;
+
+#[tokio::test]
+async fn serves_requests_until_shutdown() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    let (shutdown, shutdown_requested) = oneshot::channel();
+    let shutdown_signal = async {
+        shutdown_requested.await.ok();
+    };
+    let server = tokio::spawn({{crate_name}}_cli::serve(listener, shutdown_signal));
+
+    assert_eq!(get(address, "/health").await, (200, "ok".into()));
+    assert_eq!(get(address, "/add/2/2").await, (200, "4".into()));
+    assert_eq!(
+        get(address, "/add/18446744073709551615/1").await,
+        (400, "integer overflow".into())
+    );
+
+    shutdown.send(()).unwrap();
+    server.await.unwrap().unwrap();
+    assert!(TcpStream::connect(address).await.is_err());
+}
+
+/// Sends a `GET` request and returns the status code and body.
+async fn get(address: SocketAddr, path: &str) -> (u16, String) {
+    let mut stream = TcpStream::connect(address).await.unwrap();
+    let request = format!("GET {path} HTTP/1.1\r\nHost: {address}\r\nConnection: close\r\n\r\n");
+    stream.write_all(request.as_bytes()).await.unwrap();
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await.unwrap();
+    let (head, body) = response.split_once("\r\n\r\n").unwrap();
+    let status = head.split(' ').nth(1).unwrap().parse().unwrap();
+    (status, body.to_string())
+}
@@ -0,0 +1,257 @@
+//! Development tasks for {{project-name}}.
+//!
+//! Each task installs the tools it needs, then runs them from the workspace root,
+//! so it behaves the same in a terminal, VSCode (`.vscode/tasks.json`) and CI.
+//!
+//! # Getting Started
+//! Add new tasks to [`Task`], then handle them in [`run`].
+//!
+//! # Running
+//! ```bash
+//! cargo xtask --help
+//! ```
+
+use clap::{Parser, Subcommand};
+use std::error::Error;
+use std::path::Path;
+use std::process::{Command, ExitCode};
+{%- if build_c_libs or bench %}
+
+/// Name of the library package.
+const PACKAGE: &str = "{{project-name}}";
+{%- endif %}
+{%- if xplat %}
+
+/// Targets tested by `cross-test` when none are given.
+const CROSS_TARGETS: &[&str] = &[
+    "x86_64-unknown-linux-gnu",
+    "i686-unknown-linux-gnu",
+    "x86_64-pc-windows-gnu",
+    "i686-pc-windows-gnu",
+{%- if big_endian %}
+    "powerpc-unknown-linux-gnu",
+    "powerpc64-unknown-linux-gnu",
+{%- endif %}
+];
+{%- endif %}
+
+/// Development tasks for {{project-name}}.
+#[derive(Debug, Parser)]
+#[command(about, long_about = None)]
+struct Cli {
+    #[command(subcommand)]
+    task: Task,
+}
+
+#[derive(Debug, Subcommand)]
+enum Task {
+{%- if build_c_libs %}
+    /// Updates the committed C, C++ and C# bindings in `bindings`.
+    Bindings {
+        /// Checks that the committed bindings are up to date instead of updating them.
+        #[arg(long)]
+        check: bool,
+    },
+{%- endif %}
+    /// Collects code coverage with cargo-tarpaulin.
+    Coverage,
+{%- if miri %}
+    /// Runs the tests under Miri to detect undefined behaviour.
+    Miri {
+        /// Target to run the tests for, e.g. `powerpc64-unknown-linux-gnu` for big endian.
+        #[arg(long)]
+        target: Option<String>,
+    },
+{%- endif %}
+{%- if fuzz %}
+    /// Lists the fuzz targets, or runs one of them.
+    Fuzz {
+        /// Fuzz target to run. Lists the fuzz targets if omitted.
+        target: Option<String>,
+    },
+{%- endif %}
+{%- if bench %}
+    /// Runs the benchmarks.
+    Bench,
+{%- endif %}
+{%- if build_with_pgo %}
+    /// Compares the benchmarks with and without Profile-Guided Optimization.
+    Pgo,
+{%- endif %}
+{%- if xplat %}
+    /// Runs the tests for other targets with cross.
+    CrossTest {
+        /// Targets to test. Defaults to the targets tested in CI.
+        targets: Vec<String>,
+    },
+{%- endif %}
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match run(cli.task) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Executes the task.
+///
+/// Errors are reported by [`main`] and turned into a non-zero exit code.
+fn run(task: Task) -> Result<(), Box<dyn Error>> {
+    match task {
+{%- if build_c_libs %}
+        // See `tests/bindings.rs` in the library.
+        Task::Bindings { check: false } => {
+            execute(cargo().args(["build", "-p", PACKAGE, "--features", "generate-bindings"]))
+        }
+        Task::Bindings { check: true } => {
+            execute(cargo().args(["test", "-p", PACKAGE, "--test", "bindings"]))
+        }
+{%- endif %}
+        Task::Coverage => coverage(),
+{%- if miri %}
+        Task::Miri { target } => {
+            execute(rustup().args(["+nightly", "component", "add", "miri"]))?;
+            let mut command = cargo();
+            command.args(["+nightly", "miri", "test"]);
+            command.args(["--workspace", "--exclude", "xtask"]);
+{%- if service %}
+            // Miri can't run the network I/O in the service tests.
+            command.args(["--exclude", "{{project-name}}-cli"]);
+{%- endif %}
+            if let Some(target) = target {
+                command.args(["--target", &target]);
+            }
+            execute(&mut command)
+        }
+{%- endif %}
+{%- if fuzz %}
+        Task::Fuzz { target } => {
+            install(&["cargo", "fuzz", "--version"], &["cargo-fuzz"])?;
+            match target {
+                Some(target) => execute(cargo().args(["+nightly", "fuzz", "run", &target])),
+                None => execute(cargo().args(["+nightly", "fuzz", "list"])),
+            }
+        }
+{%- endif %}
+{%- if bench %}
+        Task::Bench => execute(cargo().args(["bench", "-p", PACKAGE])),
+{%- endif %}
+{%- if build_with_pgo %}
+        // See the Profile-Guided Optimization page in the documentation.
+        Task::Pgo => {
+            install(&["cargo", "pgo", "--version"], &["cargo-pgo"])?;
+            execute(rustup().args(["component", "add", "llvm-tools-preview"]))?;
+            execute(cargo().args(["pgo", "instrument", "test", "--"]).args([
+                "--bench",
+                "my_benchmark",
+                "--features",
+                "pgo",
+            ]))?;
+            execute(cargo().args(["bench", "-p", PACKAGE]))?;
+            execute(cargo().args(["pgo", "optimize", "bench"]))
+        }
+{%- endif %}
+{%- if xplat %}
+        Task::CrossTest { targets } => {
+            let cross = ["cross", "--git", "https://github.com/cross-rs/cross"];
+            install(&["cross", "--version"], &cross)?;
+
+            let targets = if targets.is_empty() {
+                CROSS_TARGETS.iter().map(ToString::to_string).collect()
+            } else {
+                targets
+            };
+            for target in targets {
+                let mut command = Command::new("cross");
+                command.args(["test", "--target", &target]);
+{%- if build_c_libs %}
+                // Lets `tests/c_abi.rs` find the cbindgen configs. See `Cross.toml`.
+                command.env("C_ABI_CONFIG_DIR", repository_dir().join(".github"));
+{%- endif %}
+                execute(&mut command)?;
+            }
+            Ok(())
+        }
+{%- endif %}
+    }
+}
+
+/// Writes `cobertura.xml` for Coverage Gutters and `tarpaulin-report.html`.
+fn coverage() -> Result<(), Box<dyn Error>> {
+    install(&["cargo", "tarpaulin", "--version"], &["cargo-tarpaulin"])?;
+
+    let mut command = cargo();
+    command.args(["tarpaulin", "--skip-clean"]);
+    command.args(["--workspace", "--exclude", "xtask"]);
+    command.args(["--out", "Xml", "--out", "Html", "--engine", "llvm"]);
+    command.args(["--target-dir", "target/coverage-build"]);
+    execute(&mut command)
+}
+
+fn cargo() -> Command {
+    Command::new("cargo")
+}
+{%- if miri or build_with_pgo %}
+
+fn rustup() -> Command {
+    Command::new("rustup")
+}
+{%- endif %}
+
+/// Installs a tool with `cargo install {package}`, unless running `check` succeeds.
+fn install(check: &[&str], package: &[&str]) -> Result<(), Box<dyn Error>> {
+    let (program, args) = check.split_first().unwrap();
+    let installed = Command::new(program).args(args).output();
+    if installed.is_ok_and(|output| output.status.success()) {
+        return Ok(());
+    }
+
+    execute(cargo().arg("install").args(package))
+}
+
+/// Runs `command` in the workspace root and fails if it doesn't succeed.
+fn execute(command: &mut Command) -> Result<(), Box<dyn Error>> {
+    command.current_dir(workspace_dir());
+    eprintln!("running {command:?}");
+
+    let status = command.status()?;
+    if !status.success() {
+        return Err(format!("{command:?} failed with {status}").into());
+    }
+
+    Ok(())
+}
+
+/// The `src` directory containing the workspace `Cargo.toml`.
+fn workspace_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+{%- if xplat and build_c_libs %}
+
+/// The repository root containing `.github`.
+fn repository_dir() -> &'static Path {
+    workspace_dir().parent().unwrap()
+}
+{%- endif %}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn verify_cli() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn parses_coverage() {
+        let cli = Cli::try_parse_from(["xtask", "coverage"]).unwrap();
+        assert!(matches!(cli.task, Task::Coverage));
+    }
+}
@@ -1,4 +1,3 @@
-
 // Example of how to include a 2nd file.
 mod util;
 
@@ -9,19 +8,19 @@ fn fibonacci(n: u64) -> u64 {
     match n {
         0 => 1,
         1 => 1,
-        n => fibonacci(n-1) + fibonacci(n-2),
+        n => fibonacci(n - 1) + fibonacci(n - 2),
     }
 }
 
 fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("fib 20", |b| b.iter(|| fibonacci(black_box(20))));
+{%- if build_with_pgo %}
 
-    {% if build_with_pgo %}
     #[cfg(not(feature = "pgo"))]
     {
         // Benchmarks excluded from PGO run.
     }
-    {% endif %}
+{%- endif %}
 }
 
 criterion_group! {
@@ -30,4 +29,4 @@ criterion_group! {
     targets = criterion_benchmark
 }
 
-criterion_main!(benches);
\ No newline at end of file
+criterion_main!(benches);
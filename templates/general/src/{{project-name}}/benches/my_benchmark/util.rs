@@ -0,0 +1 @@
+//! Helpers for the benchmarks in `main.rs`.
@@ -1,12 +1,35 @@
+{%- if build_c_libs -%}
+#[cfg(feature = "generate-bindings")]
+#[path = "build/bindings.rs"]
+mod bindings;
+
+{% endif -%}
 fn main() {
     // Build time scripts go here. If you have nothing to do here, you can remove this file.
-{% if build_csharp_libs -%}
-    csbindgen::Builder::default()
-        .input_extern_file("src/exports.rs")
-        .csharp_dll_name("{{crate_name}}")
-        .csharp_class_accessibility("public")
-        .csharp_namespace("{{crate_name}}.Net.Sys")
-        .generate_csharp_file("../bindings/csharp/NativeMethods.g.cs")
-        .unwrap();
-{% endif %}
+{%- if build_c_libs %}
+    // Lets `tests/c_abi.rs` compile C code for the same target as the Rust tests.
+    let target = std::env::var("TARGET").unwrap();
+    let host = std::env::var("HOST").unwrap();
+    println!("cargo:rustc-env=C_ABI_TARGET={target}");
+    println!("cargo:rustc-env=C_ABI_HOST={host}");
+
+    #[cfg(feature = "generate-bindings")]
+    generate_bindings();
+{%- endif %}
 }
+{%- if build_c_libs %}
+
+/// Updates the committed bindings in `../bindings`.
+/// `tests/bindings.rs` fails if they are out of date.
+#[cfg(feature = "generate-bindings")]
+fn generate_bindings() {
+    bindings::generate(std::path::Path::new("../bindings"));
+
+    // Printing `rerun-if-changed` disables the default of rerunning on any change.
+    println!("cargo:rerun-if-changed=src");
+    println!("cargo:rerun-if-changed=build");
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=../../.github/cbindgen_c.toml");
+    println!("cargo:rerun-if-changed=../../.github/cbindgen_cpp.toml");
+}
+{%- endif %}
@@ -0,0 +1,99 @@
+//! Generates the bindings in `src/bindings` from the C exports.
+//!
+//! Shared by `build.rs`, which updates the committed bindings with the `generate-bindings` feature,
+{%- if reloaded3_mod %}
+//! and `tests/bindings.rs`, which checks that they are up to date.
+{%- else %}
+//! `tests/bindings.rs`, which checks that they are up to date, and `tests/c_abi.rs`.
+{%- endif %}
+
+{% if build_csharp_libs -%}
+use std::fs;
+{% endif -%}
+use std::path::{% if reloaded3_mod %}Path{% else %}{Path, PathBuf}{% endif %};
+
+/// C header generated with `.github/cbindgen_c.toml`.
+const C_HEADER: &str = "c/{{project-name}}.h";
+{%- unless reloaded3_mod %}
+/// C++ header generated with `.github/cbindgen_cpp.toml`.
+const CPP_HEADER: &str = "cpp/{{project-name}}.hpp";
+{%- endunless %}
+{%- if build_csharp_libs %}
+/// C# P/Invoke declarations generated with csbindgen.
+const CSHARP_BINDINGS: &str = "csharp/NativeMethods.g.cs";
+{%- endif %}
+
+/// Writes all bindings to `out_dir`, which has the same layout as `src/bindings`.
+///
+/// Returns the generated files, relative to `out_dir`.
+pub fn generate(out_dir: &Path) -> &'static [&'static str] {
+    generate_header("cbindgen_c.toml", &out_dir.join(C_HEADER));
+{%- unless reloaded3_mod %}
+    generate_header("cbindgen_cpp.toml", &out_dir.join(CPP_HEADER));
+{%- endunless %}
+{%- if build_csharp_libs %}
+    generate_csharp(&out_dir.join(CSHARP_BINDINGS));
+    &[C_HEADER{% unless reloaded3_mod %}, CPP_HEADER{% endunless %}, CSHARP_BINDINGS]
+{%- else %}
+    &[C_HEADER{% unless reloaded3_mod %}, CPP_HEADER{% endunless %}]
+{%- endif %}
+}
+
+/// Generates a header using one of the cbindgen configs in `.github`.
+{% unless reloaded3_mod %}pub {% endunless %}fn generate_header(config: &str, header: &Path) {
+    let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+{%- if reloaded3_mod %}
+    let config = crate_dir.join("../../.github").join(config);
+{%- else %}
+    let config = config_dir().join(config);
+{%- endif %}
+
+    cbindgen::Builder::new()
+        .with_crate(crate_dir)
+        .with_config(cbindgen::Config::from_file(config).unwrap())
+        .generate()
+        .expect("failed to generate header")
+        .write_to_file(header);
+}
+{%- unless reloaded3_mod %}
+
+/// Directory with the cbindgen configs.
+///
+/// cross only mounts the workspace, so CI passes the `.github` directory in `C_ABI_CONFIG_DIR`.
+/// See `Cross.toml` in the workspace root.
+fn config_dir() -> PathBuf {
+    match std::env::var_os("C_ABI_CONFIG_DIR") {
+        Some(dir) => PathBuf::from(dir),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).join("../../.github"),
+    }
+}
+{%- endunless %}
+{%- if build_csharp_libs %}
+
+/// Generates the C# bindings. Add new files in `src/exports` here.
+fn generate_csharp(output: &Path) {
+    fs::create_dir_all(output.parent().unwrap()).unwrap();
+
+    // Paths are relative to the crate directory, which is the working directory
+    // of both build scripts and tests.
+    csbindgen::Builder::default()
+        .input_extern_file("src/exports.rs")
+{%- if reloaded3_mod %}
+        .input_extern_file("src/exports/entry.rs")
+        .input_extern_file("src/exports/error.rs")
+{%- else %}
+        .input_extern_file("src/exports/counter.rs")
+        .input_extern_file("src/exports/error.rs")
+        .input_extern_file("src/exports/ffi.rs")
+{%- if tracing %}
+        .input_extern_file("src/exports/logging.rs")
+{%- endif %}
+{%- endif %}
+        .input_extern_file("src/exports/version.rs")
+        .csharp_dll_name("{{crate_name}}")
+        .csharp_class_accessibility("public")
+        .csharp_namespace("{{crate_name}}.Net.Sys")
+        .generate_csharp_file(output)
+        .unwrap();
+}
+{%- endif %}
@@ -1,4 +1,56 @@
+pub mod counter;
+pub mod error;
+pub mod ffi;
+mod handle;
+{%- if std-by-default or no_std-by-default %}
+pub mod hooks;
+{%- endif %}
+{%- if tracing %}
+pub mod logging;
+{%- endif %}
+{%- if std-by-default or no_std-by-default %}
+#[cfg(all(feature = "no-std-runtime", not(feature = "std")))]
+pub mod runtime;
+{%- endif %}
+pub mod version;
+
+use error::{ffi_guard, to_status, FfiStatus};
+
 #[no_mangle]
 pub extern "C" fn it_works() -> i32 {
-    1
+    ffi_guard(0, || 1)
+}
+
+/// Adds two numbers together, failing if the result overflows.
+///
+/// # Parameters
+///
+/// - `left`: The first number.
+/// - `right`: The second number.
+/// - `out_result`: Receives the sum on success.
+///
+/// # Returns
+///
+/// - [`FfiStatus::Ok`] on success.
+/// - [`FfiStatus::Error`] if the sum overflows.
+/// - [`FfiStatus::NullPointer`] if `out_result` is null.
+/// - [`FfiStatus::Panic`] if the library panicked.
+///
+/// # Safety
+///
+/// `out_result` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn {{crate_name}}_checked_add(
+    left: u64,
+    right: u64,
+    out_result: *mut u64,
+) -> FfiStatus {
+    ffi_guard(FfiStatus::Panic, || {
+        if out_result.is_null() {
+            return FfiStatus::NullPointer;
+        }
+
+        let sum = crate::checked_add(left, right).ok_or("integer overflow");
+        to_status(sum.map(|sum| *out_result = sum))
+    })
 }
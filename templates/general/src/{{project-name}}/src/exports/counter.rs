@@ -0,0 +1,157 @@
+//! C exports for [`Counter`], showing the opaque handle lifecycle.
+//!
+//! C callers receive a `Counter*` from [`{{crate_name}}_counter_new`], pass it to the other
+//! functions, and release it with [`{{crate_name}}_counter_free`].
+
+use super::error::{ffi_guard, FfiStatus};
+use super::handle::{free_handle, handle_mut, handle_ref, into_handle};
+use crate::Counter;
+use core::ptr::null_mut;
+
+/// Creates a new counter starting at zero.
+///
+/// # Returns
+///
+/// An owning pointer to the counter, or null if creation failed.
+/// The counter must be released with [`{{crate_name}}_counter_free`].
+#[no_mangle]
+pub extern "C" fn {{crate_name}}_counter_new() -> *mut Counter {
+    ffi_guard(null_mut(), || into_handle(Counter::new()))
+}
+
+/// Releases a counter created by [`{{crate_name}}_counter_new`].
+///
+/// # Returns
+///
+/// - [`FfiStatus::Ok`] if the counter was released.
+/// - [`FfiStatus::NullPointer`] if `counter` is null.
+/// - [`FfiStatus::InvalidHandle`] if `counter` was already released (debug builds only).
+///
+/// # Safety
+///
+/// `counter` must be null or a pointer returned by [`{{crate_name}}_counter_new`].
+/// It must not be used after this call.
+#[no_mangle]
+pub unsafe extern "C" fn {{crate_name}}_counter_free(counter: *mut Counter) -> FfiStatus {
+    ffi_guard(FfiStatus::Panic, || free_handle(counter))
+}
+
+/// Increments the counter.
+///
+/// # Parameters
+///
+/// - `counter`: The counter to increment.
+/// - `out_value`: Receives the new value on success.
+///
+/// # Safety
+///
+/// `counter` must be a live pointer returned by [`{{crate_name}}_counter_new`].
+/// `out_value` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn {{crate_name}}_counter_increment(
+    counter: *mut Counter,
+    out_value: *mut u64,
+) -> FfiStatus {
+    ffi_guard(FfiStatus::Panic, || {
+        if out_value.is_null() {
+            return FfiStatus::NullPointer;
+        }
+
+        match handle_mut(counter) {
+            Ok(counter) => {
+                *out_value = counter.increment();
+                FfiStatus::Ok
+            }
+            Err(status) => status,
+        }
+    })
+}
+
+/// Gets the current value of the counter.
+///
+/// # Parameters
+///
+/// - `counter`: The counter to read.
+/// - `out_value`: Receives the current value on success.
+///
+/// # Safety
+///
+/// `counter` must be a live pointer returned by [`{{crate_name}}_counter_new`].
+/// `out_value` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn {{crate_name}}_counter_value(
+    counter: *const Counter,
+    out_value: *mut u64,
+) -> FfiStatus {
+    ffi_guard(FfiStatus::Panic, || {
+        if out_value.is_null() {
+            return FfiStatus::NullPointer;
+        }
+
+        match handle_ref(counter) {
+            Ok(counter) => {
+                *out_value = counter.value();
+                FfiStatus::Ok
+            }
+            Err(status) => status,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::ptr::null;
+
+    #[test]
+    fn counter_lifecycle() {
+        let counter = {{crate_name}}_counter_new();
+        assert!(!counter.is_null());
+
+        let mut value = 0;
+        let status = unsafe { {{crate_name}}_counter_increment(counter, &mut value) };
+        assert_eq!(status, FfiStatus::Ok);
+        let status = unsafe { {{crate_name}}_counter_value(counter, &mut value) };
+        assert_eq!(status, FfiStatus::Ok);
+        assert_eq!(value, 1);
+
+        let status = unsafe { {{crate_name}}_counter_free(counter) };
+        assert_eq!(status, FfiStatus::Ok);
+    }
+
+    #[test]
+    fn null_counter_is_rejected() {
+        let mut value = 0;
+        let status = unsafe { {{crate_name}}_counter_value(null(), &mut value) };
+        assert_eq!(status, FfiStatus::NullPointer);
+
+        let status = unsafe { {{crate_name}}_counter_free(null_mut()) };
+        assert_eq!(status, FfiStatus::NullPointer);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn double_free_is_detected() {
+        let counter = {{crate_name}}_counter_new();
+        let status = unsafe { {{crate_name}}_counter_free(counter) };
+        assert_eq!(status, FfiStatus::Ok);
+
+        let status = unsafe { {{crate_name}}_counter_free(counter) };
+        assert_eq!(status, FfiStatus::InvalidHandle);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn racing_frees_only_free_once() {
+        // Raw pointers aren't `Send`, so pass the address instead.
+        let counter = {{crate_name}}_counter_new() as usize;
+        let free = || unsafe { {{crate_name}}_counter_free(counter as *mut Counter) };
+        let statuses = std::thread::scope(|scope| {
+            let other = scope.spawn(free);
+            [free(), other.join().unwrap()]
+        });
+
+        assert!(statuses.contains(&FfiStatus::Ok));
+        assert!(statuses.contains(&FfiStatus::InvalidHandle));
+    }
+}
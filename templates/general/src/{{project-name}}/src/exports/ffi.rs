@@ -0,0 +1,248 @@
+//! Helpers for passing strings and byte slices across the C boundary.
+//!
+//! - Inputs: `str_from_ptr` and `slice_from_raw` validate pointers coming from C.
+//! - Caller-provided buffers: `write_str_to_buffer` copies a string and reports the required length.
+//! - Rust-allocated strings: `into_c_string` hands out a string that C releases with
+//!   [`{{crate_name}}_free_string`].
+
+use super::error::{ffi_guard, set_last_error, FfiStatus};
+use alloc::ffi::CString;
+use alloc::format;
+use alloc::string::String;
+use core::ffi::{c_char, CStr};
+use core::ptr::{copy_nonoverlapping, null_mut};
+
+/// Reads a null-terminated UTF-8 string.
+///
+/// # Safety
+///
+/// `ptr` must be null or point to a null-terminated string that outlives `'a`.
+pub(crate) unsafe fn str_from_ptr<'a>(ptr: *const c_char) -> Result<&'a str, FfiStatus> {
+    if ptr.is_null() {
+        return Err(FfiStatus::NullPointer);
+    }
+
+    CStr::from_ptr(ptr).to_str().map_err(|error| {
+        set_last_error(error);
+        FfiStatus::InvalidUtf8
+    })
+}
+
+/// Reads a byte slice. A null pointer is accepted if `len` is zero.
+///
+/// # Safety
+///
+/// `ptr` must be valid for reads of `len` bytes for the lifetime `'a`.
+pub(crate) unsafe fn slice_from_raw<'a>(ptr: *const u8, len: usize) -> Result<&'a [u8], FfiStatus> {
+    match (ptr.is_null(), len) {
+        (true, 0) => Ok(&[]),
+        (true, _) => Err(FfiStatus::NullPointer),
+        (false, _) => Ok(core::slice::from_raw_parts(ptr, len)),
+    }
+}
+
+/// Copies `value` with a null terminator into a caller-provided buffer.
+///
+/// The required length, including the null terminator, is always written to `out_required`
+/// (if not null), so callers can pass a null `buffer` with `buffer_len` zero to query the size.
+///
+/// # Safety
+///
+/// `buffer` must be valid for writes of `buffer_len` bytes.
+/// `out_required` must be null or valid for writes.
+pub(crate) unsafe fn write_str_to_buffer(
+    value: &str,
+    buffer: *mut c_char,
+    buffer_len: usize,
+    out_required: *mut usize,
+) -> FfiStatus {
+    let required = value.len() + 1;
+    if !out_required.is_null() {
+        *out_required = required;
+    }
+
+    if buffer_len < required {
+        return FfiStatus::BufferTooSmall;
+    }
+    if buffer.is_null() {
+        return FfiStatus::NullPointer;
+    }
+
+    copy_nonoverlapping(value.as_ptr(), buffer.cast::<u8>(), value.len());
+    *buffer.add(value.len()) = 0;
+    FfiStatus::Ok
+}
+
+/// Moves `value` to a null-terminated string owned by the caller.
+///
+/// Returns null if `value` contains an interior null byte.
+pub(crate) fn into_c_string(value: String) -> *mut c_char {
+    match CString::new(value) {
+        Ok(value) => value.into_raw(),
+        Err(error) => {
+            set_last_error(error);
+            null_mut()
+        }
+    }
+}
+
+/// Releases a string returned by this library.
+///
+/// # Safety
+///
+/// `value` must be null or a string returned by this library that has not been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn {{crate_name}}_free_string(value: *mut c_char) {
+    ffi_guard((), || {
+        if !value.is_null() {
+            drop(CString::from_raw(value));
+        }
+    })
+}
+
+/// Creates a greeting for `name`.
+///
+/// # Returns
+///
+/// A string that must be released with [`{{crate_name}}_free_string`],
+/// or null if `name` is null or not valid UTF-8.
+///
+/// # Safety
+///
+/// `name` must be null or a null-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn {{crate_name}}_greet(name: *const c_char) -> *mut c_char {
+    ffi_guard(null_mut(), || match str_from_ptr(name) {
+        Ok(name) => into_c_string(format!("Hello, {name}!")),
+        Err(_) => null_mut(),
+    })
+}
+
+/// Writes a greeting for `name` into a caller-provided buffer.
+///
+/// # Parameters
+///
+/// - `name`: Null-terminated UTF-8 name.
+/// - `buffer`: Buffer to write the greeting to. May be null if `buffer_len` is zero.
+/// - `buffer_len`: Length of `buffer` in bytes.
+/// - `out_required`: Receives the length needed, including the null terminator. May be null.
+///
+/// # Returns
+///
+/// - [`FfiStatus::Ok`] if the greeting was written.
+/// - [`FfiStatus::BufferTooSmall`] if `buffer_len` is smaller than the required length.
+/// - [`FfiStatus::NullPointer`] if `name` is null.
+/// - [`FfiStatus::InvalidUtf8`] if `name` is not valid UTF-8.
+///
+/// # Safety
+///
+/// `name` must be null or a null-terminated string.
+/// `buffer` must be valid for writes of `buffer_len` bytes.
+/// `out_required` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn {{crate_name}}_greet_into(
+    name: *const c_char,
+    buffer: *mut c_char,
+    buffer_len: usize,
+    out_required: *mut usize,
+) -> FfiStatus {
+    ffi_guard(FfiStatus::Panic, || match str_from_ptr(name) {
+        Ok(name) => {
+            let greeting = format!("Hello, {name}!");
+            write_str_to_buffer(&greeting, buffer, buffer_len, out_required)
+        }
+        Err(status) => status,
+    })
+}
+
+/// Sums all bytes in `data`.
+///
+/// # Parameters
+///
+/// - `data`: Bytes to sum. May be null if `data_len` is zero.
+/// - `data_len`: Number of bytes in `data`.
+/// - `out_sum`: Receives the sum on success.
+///
+/// # Safety
+///
+/// `data` must be valid for reads of `data_len` bytes. `out_sum` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn {{crate_name}}_sum_bytes(
+    data: *const u8,
+    data_len: usize,
+    out_sum: *mut u64,
+) -> FfiStatus {
+    ffi_guard(FfiStatus::Panic, || {
+        if out_sum.is_null() {
+            return FfiStatus::NullPointer;
+        }
+
+        match slice_from_raw(data, data_len) {
+            Ok(data) => {
+                *out_sum = data.iter().map(|&byte| u64::from(byte)).sum();
+                FfiStatus::Ok
+            }
+            Err(status) => status,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::ptr::null;
+
+    fn greet_into(name: &CStr, buffer: &mut [c_char], out_required: &mut usize) -> FfiStatus {
+        let (ptr, len) = (buffer.as_mut_ptr(), buffer.len());
+        unsafe { {{crate_name}}_greet_into(name.as_ptr(), ptr, len, out_required) }
+    }
+
+    fn sum_bytes(data: *const u8, data_len: usize, out_sum: &mut u64) -> FfiStatus {
+        unsafe { {{crate_name}}_sum_bytes(data, data_len, out_sum) }
+    }
+
+    #[test]
+    fn greet_round_trip() {
+        let greeting = unsafe { {{crate_name}}_greet(c"World".as_ptr()) };
+        assert!(!greeting.is_null());
+
+        let message = unsafe { CStr::from_ptr(greeting) };
+        assert_eq!(message.to_str().unwrap(), "Hello, World!");
+        unsafe { {{crate_name}}_free_string(greeting) };
+    }
+
+    #[test]
+    fn greet_rejects_invalid_utf8() {
+        let name = [0xFFu8, 0];
+        let greeting = unsafe { {{crate_name}}_greet(name.as_ptr().cast()) };
+        assert!(greeting.is_null());
+    }
+
+    #[test]
+    fn greet_into_reports_required_length() {
+        let mut required = 0;
+        let status = greet_into(c"World", &mut [], &mut required);
+        assert_eq!(status, FfiStatus::BufferTooSmall);
+        assert_eq!(required, "Hello, World!".len() + 1);
+
+        let mut buffer = [0 as c_char; 32];
+        let status = greet_into(c"World", &mut buffer, &mut required);
+        assert_eq!(status, FfiStatus::Ok);
+
+        let message = unsafe { CStr::from_ptr(buffer.as_ptr()) };
+        assert_eq!(message.to_str().unwrap(), "Hello, World!");
+    }
+
+    #[test]
+    fn sum_bytes_accepts_empty_input() {
+        let mut sum = 1;
+        assert_eq!(sum_bytes(null(), 0, &mut sum), FfiStatus::Ok);
+        assert_eq!(sum, 0);
+
+        let data = [1u8, 2, 3];
+        let status = sum_bytes(data.as_ptr(), data.len(), &mut sum);
+        assert_eq!(status, FfiStatus::Ok);
+        assert_eq!(sum, 6);
+        assert_eq!(sum_bytes(null(), 1, &mut sum), FfiStatus::NullPointer);
+    }
+}
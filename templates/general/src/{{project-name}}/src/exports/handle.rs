@@ -0,0 +1,112 @@
+//! Helpers for passing Rust objects to C as opaque pointers.
+//!
+//! Objects are boxed and handed out with [`into_handle`], borrowed with [`handle_ref`] and
+//! [`handle_mut`], and destroyed with [`free_handle`].
+//!
+//! In debug builds, live handles are tracked with their type, so that double frees,
+//! use-after-free and passing a handle to a function for another type are reported as
+//! [`FfiStatus::InvalidHandle`] instead of corrupting memory.
+
+use super::error::FfiStatus;
+use alloc::boxed::Box;
+#[cfg(debug_assertions)]
+use core::any::TypeId;
+
+/// The type and address of every live handle.
+#[cfg(debug_assertions)]
+static LIVE_HANDLES: spin::Mutex<alloc::collections::BTreeSet<(TypeId, usize)>> =
+    spin::Mutex::new(alloc::collections::BTreeSet::new());
+
+/// Moves `value` to the heap and returns an owning pointer to it.
+pub(crate) fn into_handle<T: 'static>(value: T) -> *mut T {
+    let handle = Box::into_raw(Box::new(value));
+    #[cfg(debug_assertions)]
+    LIVE_HANDLES.lock().insert(key(handle));
+    handle
+}
+
+/// Destroys a handle created by [`into_handle`].
+///
+/// # Safety
+///
+/// `handle` must be null or a pointer returned by [`into_handle`] for the same `T`,
+/// which has not been freed yet.
+pub(crate) unsafe fn free_handle<T: 'static>(handle: *mut T) -> FfiStatus {
+    if handle.is_null() {
+        return FfiStatus::NullPointer;
+    }
+
+    // Checks and removes the handle under one lock, so only one of two racing frees succeeds.
+    #[cfg(debug_assertions)]
+    if !LIVE_HANDLES.lock().remove(&key(handle)) {
+        return invalid_handle(handle);
+    }
+
+    drop(Box::from_raw(handle));
+    FfiStatus::Ok
+}
+
+/// Borrows the object behind a handle created by [`into_handle`].
+///
+/// # Safety
+///
+/// `handle` must be null or a live pointer returned by [`into_handle`] for the same `T`.
+/// The object must not be mutated while the reference is alive.
+pub(crate) unsafe fn handle_ref<'a, T: 'static>(handle: *const T) -> Result<&'a T, FfiStatus> {
+    validate(handle)?;
+    Ok(&*handle)
+}
+
+/// Mutably borrows the object behind a handle created by [`into_handle`].
+///
+/// # Safety
+///
+/// `handle` must be null or a live pointer returned by [`into_handle`] for the same `T`.
+/// The object must not be accessed through any other reference while this one is alive.
+pub(crate) unsafe fn handle_mut<'a, T: 'static>(handle: *mut T) -> Result<&'a mut T, FfiStatus> {
+    validate(handle)?;
+    Ok(&mut *handle)
+}
+
+fn validate<T: 'static>(handle: *const T) -> Result<(), FfiStatus> {
+    if handle.is_null() {
+        return Err(FfiStatus::NullPointer);
+    }
+
+    #[cfg(debug_assertions)]
+    if !LIVE_HANDLES.lock().contains(&key(handle)) {
+        return Err(invalid_handle(handle));
+    }
+
+    Ok(())
+}
+
+/// Identifies a handle by its type as well as its address, so a handle can't be freed as another type.
+#[cfg(debug_assertions)]
+fn key<T: 'static>(handle: *const T) -> (TypeId, usize) {
+    (TypeId::of::<T>(), handle as usize)
+}
+
+#[cfg(debug_assertions)]
+fn invalid_handle<T>(handle: *const T) -> FfiStatus {
+    super::error::set_last_error(format_args!("invalid or already freed handle: {handle:p}"));
+    FfiStatus::InvalidHandle
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn handle_of_another_type_is_rejected() {
+        let handle = into_handle(1u32);
+        let status = unsafe { free_handle(handle.cast::<u64>()) };
+        assert_eq!(status, FfiStatus::InvalidHandle);
+        let status = unsafe { handle_ref(handle.cast::<u64>()) }.err();
+        assert_eq!(status, Some(FfiStatus::InvalidHandle));
+
+        let status = unsafe { free_handle(handle) };
+        assert_eq!(status, FfiStatus::Ok);
+    }
+}
@@ -0,0 +1,23 @@
+//! Functions the host passes to `{{crate_name}}_init_runtime` in `runtime.rs`.
+//!
+//! The runtime is only compiled without `std`, so [`FfiHooks`] lives here instead, where
+//! `tests/c_abi.rs` can check its layout.
+
+use core::ffi::{c_char, c_void};
+
+/// Memory and panic functions provided by the host.
+///
+/// All of them are required. `{{crate_name}}_init_runtime` rejects hooks with a null function.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct FfiHooks {
+    /// Allocates `size` bytes aligned to `align`, a power of two. Returns null on failure.
+    pub alloc: Option<unsafe extern "C" fn(size: usize, align: usize) -> *mut c_void>,
+    /// Frees memory returned by `alloc`, with the same `size` and `align`.
+    pub free: Option<unsafe extern "C" fn(ptr: *mut c_void, size: usize, align: usize)>,
+    /// Called when the library panics, with `len` bytes of UTF-8 text followed by a null terminator.
+    ///
+    /// Must not return, e.g. call `abort` or reset the device. The library can't continue after a
+    /// panic, so if it does return, the library aborts the process, or traps without an OS.
+    pub panic: Option<unsafe extern "C" fn(message: *const c_char, len: usize)>,
+}
@@ -0,0 +1,222 @@
+//! Forwards the library's `tracing` events to a callback registered from C.
+//!
+//! Hosts call [`{{crate_name}}_set_log_callback`] once at startup, e.g. to write the library's
+//! messages to their own log. Spans are not forwarded, only the events inside them.
+//! Records from dependencies that use the `log` crate are converted to events, and forwarded too.
+{%- if std-by-default or no_std-by-default %}
+//! This needs the `std` feature.
+{%- endif %}
+//!
+//! The callback is installed as the global `tracing` subscriber, so it is not available
+//! when the library is used from a Rust program that installs its own subscriber.
+
+use super::error::{ffi_guard, to_status, FfiStatus};
+use alloc::string::String;
+use core::ffi::c_char;
+use core::fmt::{self, Write};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::subscriber::{self, Interest};
+use tracing::{Event, Level, Metadata, Subscriber};
+{%- if std-by-default or no_std-by-default %}
+#[cfg(feature = "std")]
+{%- endif %}
+use tracing_log::LogTracer;
+
+/// Severity of a log message, from most to least severe.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FfiLogLevel {
+    /// Something failed.
+    Error = 1,
+    /// Something unexpected happened, but the library recovered.
+    Warn = 2,
+    /// High level progress.
+    Info = 3,
+    /// Details useful when debugging.
+    Debug = 4,
+    /// Very verbose details, such as function return values.
+    Trace = 5,
+}
+
+impl From<Level> for FfiLogLevel {
+    fn from(level: Level) -> Self {
+        match level {
+            Level::ERROR => Self::Error,
+            Level::WARN => Self::Warn,
+            Level::INFO => Self::Info,
+            Level::DEBUG => Self::Debug,
+            Level::TRACE => Self::Trace,
+        }
+    }
+}
+
+/// Receives a log message, or null for no callback.
+///
+/// `message` points to `len` bytes of UTF-8 text, followed by a null terminator.
+/// It is only valid for the duration of the call.
+///
+/// The callback may be called from any thread, and must not unwind.
+pub type LogCallback =
+    Option<unsafe extern "C" fn(level: FfiLogLevel, message: *const c_char, len: usize)>;
+
+static CALLBACK: spin::RwLock<LogCallback> = spin::RwLock::new(None);
+
+/// Sets the callback that receives the library's log messages, replacing the previous one.
+///
+/// # Parameters
+///
+/// - `callback`: Function called for every message, or null to stop logging.
+///
+/// # Returns
+///
+/// - [`FfiStatus::Ok`] if the callback was set.
+/// - [`FfiStatus::Error`] if another `tracing` subscriber is already installed.
+/// - [`FfiStatus::Panic`] if the library panicked.
+#[no_mangle]
+pub extern "C" fn {{crate_name}}_set_log_callback(callback: LogCallback) -> FfiStatus {
+    ffi_guard(FfiStatus::Panic, || {
+        *CALLBACK.write() = callback;
+        if callback.is_none() {
+            return FfiStatus::Ok;
+        }
+
+        to_status(install_subscriber())
+    })
+}
+
+/// Installs [`CallbackSubscriber`] as the global subscriber, the first time it's called.
+fn install_subscriber() -> Result<(), &'static str> {
+    static INSTALLED: spin::Once<bool> = spin::Once::new();
+    let installed = INSTALLED.call_once(|| {
+        if subscriber::set_global_default(CallbackSubscriber).is_err() {
+            return false;
+        }
+
+        // Only fails if the process already set a `log` logger, which then keeps those records.
+        {%- if std-by-default or no_std-by-default %}
+        #[cfg(feature = "std")]
+        {%- endif %}
+        let _ = LogTracer::init();
+        true
+    });
+    if !installed {
+        return Err("another tracing subscriber is already installed");
+    }
+
+    Ok(())
+}
+
+fn callback() -> LogCallback {
+    *CALLBACK.read()
+}
+
+/// Formats events and passes them to the [`CALLBACK`].
+struct CallbackSubscriber;
+
+impl Subscriber for CallbackSubscriber {
+    fn register_callsite(&self, _: &'static Metadata<'static>) -> Interest {
+        // The callback can change at any time, so don't let `tracing` cache `enabled`.
+        Interest::sometimes()
+    }
+
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        callback().is_some()
+    }
+
+    fn new_span(&self, _: &Attributes<'_>) -> Id {
+        // Spans are not tracked, so they can all share an ID.
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let Some(callback) = callback() else {
+            return;
+        };
+
+        let mut message = MessageVisitor::default();
+        event.record(&mut message);
+        let mut message = message.0;
+        // Interior nulls would truncate the message on the C side, so drop them.
+        message.retain(|c| c != '\0');
+        let len = message.len();
+        message.push('\0');
+
+        let level = FfiLogLevel::from(*event.metadata().level());
+        unsafe { callback(level, message.as_ptr().cast(), len) };
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+/// Formats an event as `message key=value key=value`.
+#[derive(Default)]
+struct MessageVisitor(String);
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        // Records converted from `log` carry their origin in `log.*` fields, which are noise here.
+        if field.name().starts_with("log.") {
+            return;
+        }
+
+        if !self.0.is_empty() {
+            self.0.push(' ');
+        }
+
+        let _ = match field.name() {
+            "message" => write!(self.0, "{value:?}"),
+            name => write!(self.0, "{name}={value:?}"),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::slice;
+    use std::sync::Mutex;
+    use std::vec::Vec;
+
+    static MESSAGES: Mutex<Vec<(FfiLogLevel, String)>> = Mutex::new(Vec::new());
+
+    unsafe extern "C" fn record(level: FfiLogLevel, message: *const c_char, len: usize) {
+        let message = slice::from_raw_parts(message.cast::<u8>(), len);
+        let message = String::from_utf8(message.to_vec()).unwrap();
+        MESSAGES.lock().unwrap().push((level, message));
+    }
+
+    #[test]
+    fn callback_receives_events() {
+        let status = {{crate_name}}_set_log_callback(Some(record));
+        assert_eq!(status, FfiStatus::Ok);
+        tracing::info!(answer = 42, "hello from Rust");
+        crate::add(2, 2);
+
+        let messages = MESSAGES.lock().unwrap();
+        let info = (FfiLogLevel::Info, "hello from Rust answer=42".into());
+        assert!(messages.contains(&info), "{messages:?}");
+        let add = (FfiLogLevel::Debug, "return=4".into());
+        assert!(messages.contains(&add), "{messages:?}");
+    }
+
+    #[test]
+    {%- if std-by-default or no_std-by-default %}
+    #[cfg(feature = "std")]
+    {%- endif %}
+    fn callback_receives_log_records() {
+        let status = {{crate_name}}_set_log_callback(Some(record));
+        assert_eq!(status, FfiStatus::Ok);
+        tracing_log::log::warn!("hello from log");
+
+        let messages = MESSAGES.lock().unwrap();
+        let log = (FfiLogLevel::Warn, "hello from log".into());
+        assert!(messages.contains(&log), "{messages:?}");
+    }
+}
@@ -0,0 +1,273 @@
+//! Allocator and panic handler for building the C library without `std`.
+//!
+//! A `no_std` static library has neither, so the host provides them with
+//! [`{{crate_name}}_init_runtime`], which must be called before any other export.
+//!
+//! Only compiled with the `no-std-runtime` feature, and without `std`. Rust programs that
+//! use the crate already have an allocator and panic handler, so they must not enable it.
+
+use super::error::{ffi_guard, set_last_error, FfiStatus};
+use super::hooks::FfiHooks;
+use core::alloc::{GlobalAlloc, Layout};
+#[cfg(not(any(test, target_vendor = "apple", windows)))]
+use core::ffi::{c_int, c_void};
+use core::fmt;
+use core::ptr::null_mut;
+
+static HOOKS: spin::Once<FfiHooks> = spin::Once::new();
+
+/// Sets the allocator and panic handler used by the library. Call it once, before any other export.
+///
+/// # Parameters
+///
+/// - `hooks`: The host's functions, copied by the library.
+///
+/// # Returns
+///
+/// - [`FfiStatus::Ok`] if the hooks were set.
+/// - [`FfiStatus::Error`] if the hooks were already set.
+/// - [`FfiStatus::NullPointer`] if `hooks`, or any function in it, is null.
+///
+/// # Safety
+///
+/// `hooks` must be null or valid for reads.
+#[no_mangle]
+pub unsafe extern "C" fn {{crate_name}}_init_runtime(hooks: *const FfiHooks) -> FfiStatus {
+    ffi_guard(FfiStatus::Panic, || {
+        if hooks.is_null() {
+            return FfiStatus::NullPointer;
+        }
+        let hooks = *hooks;
+        if hooks.alloc.is_none() || hooks.free.is_none() || hooks.panic.is_none() {
+            return FfiStatus::NullPointer;
+        }
+
+        let mut newly_set = false;
+        HOOKS.call_once(|| {
+            newly_set = true;
+            hooks
+        });
+        if !newly_set {
+            set_last_error("runtime hooks are already set");
+            return FfiStatus::Error;
+        }
+
+        FfiStatus::Ok
+    })
+}
+
+/// Allocates through the host's hooks. Fails every allocation until they are set.
+struct HostAllocator;
+
+unsafe impl GlobalAlloc for HostAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        match HOOKS.get().and_then(|hooks| hooks.alloc) {
+            Some(alloc) => alloc(layout.size(), layout.align()).cast(),
+            None => null_mut(),
+        }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        if let Some(free) = HOOKS.get().and_then(|hooks| hooks.free) {
+            free(ptr.cast(), layout.size(), layout.align());
+        }
+    }
+}
+
+#[cfg(not(test))]
+#[global_allocator]
+static ALLOCATOR: HostAllocator = HostAllocator;
+
+#[cfg(not(test))]
+#[panic_handler]
+fn panic(info: &core::panic::PanicInfo<'_>) -> ! {
+    // Formatting into a `String` could allocate while the allocator is broken, so use the stack.
+    let mut message = MessageBuffer::default();
+    let _ = fmt::write(&mut message, format_args!("{info}"));
+    if let Some(panic) = HOOKS.get().and_then(|hooks| hooks.panic) {
+        let len = message.len;
+        unsafe { panic(message.terminated().as_ptr().cast(), len) };
+    }
+
+    // The hooks are unset, or the host's `panic` returned, which it must not do.
+    abort()
+}
+
+/// Stops the program, as the library can't continue after a panic.
+#[cfg(not(test))]
+fn abort() -> ! {
+    // Hosted targets link the C runtime, which has `abort`.
+    #[cfg(not(target_os = "none"))]
+    {
+        extern "C" {
+            #[link_name = "abort"]
+            fn c_abort() -> !;
+        }
+        unsafe { c_abort() }
+    }
+
+    // Without an OS, raise an illegal instruction exception for the device's fault handler.
+    #[cfg(all(target_os = "none", any(target_arch = "arm", target_arch = "aarch64")))]
+    unsafe {
+        core::arch::asm!("udf #0", options(noreturn, nomem, nostack))
+    }
+    #[cfg(all(
+        target_os = "none",
+        any(target_arch = "riscv32", target_arch = "riscv64")
+    ))]
+    unsafe {
+        core::arch::asm!("unimp", options(noreturn, nomem, nostack))
+    }
+    #[cfg(all(target_os = "none", any(target_arch = "x86", target_arch = "x86_64")))]
+    unsafe {
+        core::arch::asm!("ud2", options(noreturn, nomem, nostack))
+    }
+    // Other architectures have no stable inline assembly to trap with, so halt here instead.
+    #[cfg(all(
+        target_os = "none",
+        not(any(
+            target_arch = "arm",
+            target_arch = "aarch64",
+            target_arch = "riscv32",
+            target_arch = "riscv64",
+            target_arch = "x86",
+            target_arch = "x86_64"
+        ))
+    ))]
+    loop {
+        core::hint::spin_loop();
+    }
+}
+
+// The prebuilt `core` for targets that support unwinding references a personality routine, even
+// with `panic = "abort"`. Nothing unwinds, so it's never called, and reports a fatal error if it is.
+//
+// It's defined as a weak, hidden symbol, so it doesn't clash with the one from `std`, or from
+// another Rust static library linked into the same program. See the crate's README.
+#[cfg(not(any(test, target_vendor = "apple", windows)))]
+core::arch::global_asm!(
+    ".weak rust_eh_personality",
+    ".hidden rust_eh_personality",
+    ".set rust_eh_personality, {}",
+    sym eh_personality,
+);
+
+/// Itanium C++ ABI personality routine. Returns `_URC_FATAL_PHASE1_ERROR`.
+#[cfg(not(any(test, target_vendor = "apple", windows, target_arch = "arm")))]
+unsafe extern "C" fn eh_personality(
+    _version: c_int,
+    _actions: c_int,
+    _exception_class: u64,
+    _exception: *mut c_void,
+    _context: *mut c_void,
+) -> c_int {
+    3
+}
+
+/// ARM EHABI personality routine. Returns `_URC_FAILURE`.
+#[cfg(all(not(test), target_arch = "arm"))]
+unsafe extern "C" fn eh_personality(
+    _state: c_int,
+    _exception: *mut c_void,
+    _context: *mut c_void,
+) -> c_int {
+    9
+}
+
+/// Fixed size buffer for panic messages, which truncates messages that don't fit.
+struct MessageBuffer {
+    bytes: [u8; 256],
+    len: usize,
+}
+
+impl Default for MessageBuffer {
+    fn default() -> Self {
+        Self {
+            bytes: [0; 256],
+            len: 0,
+        }
+    }
+}
+
+impl MessageBuffer {
+    /// Returns the message followed by a null terminator.
+    fn terminated(&mut self) -> &[u8] {
+        self.bytes[self.len] = 0;
+        &self.bytes[..=self.len]
+    }
+}
+
+impl fmt::Write for MessageBuffer {
+    fn write_str(&mut self, text: &str) -> fmt::Result {
+        // Keep the last byte for the null terminator, and only cut at character boundaries.
+        let space = self.bytes.len() - 1 - self.len;
+        let mut end = text.len().min(space);
+        while !text.is_char_boundary(end) {
+            end -= 1;
+        }
+
+        self.bytes[self.len..self.len + end].copy_from_slice(&text.as_bytes()[..end]);
+        self.len += end;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::ffi::{c_char, c_void};
+    use core::fmt::Write;
+    use std::alloc::System;
+
+    unsafe extern "C" fn test_alloc(size: usize, align: usize) -> *mut c_void {
+        System
+            .alloc(Layout::from_size_align_unchecked(size, align))
+            .cast()
+    }
+
+    unsafe extern "C" fn test_free(ptr: *mut c_void, size: usize, align: usize) {
+        System.dealloc(ptr.cast(), Layout::from_size_align_unchecked(size, align));
+    }
+
+    unsafe extern "C" fn test_panic(_: *const c_char, _: usize) {}
+
+    #[test]
+    fn init_runtime_enables_allocator() {
+        let hooks = FfiHooks {
+            alloc: Some(test_alloc),
+            free: Some(test_free),
+            panic: Some(test_panic),
+        };
+        let layout = Layout::new::<u64>();
+
+        unsafe {
+            assert!(HostAllocator.alloc(layout).is_null());
+            let status = {{crate_name}}_init_runtime(core::ptr::null());
+            assert_eq!(status, FfiStatus::NullPointer);
+            let missing_free = FfiHooks {
+                free: None,
+                ..hooks
+            };
+            let status = {{crate_name}}_init_runtime(&missing_free);
+            assert_eq!(status, FfiStatus::NullPointer);
+            assert_eq!({{crate_name}}_init_runtime(&hooks), FfiStatus::Ok);
+            assert_eq!({{crate_name}}_init_runtime(&hooks), FfiStatus::Error);
+
+            let ptr = HostAllocator.alloc(layout);
+            assert!(!ptr.is_null());
+            ptr.cast::<u64>().write(42);
+            HostAllocator.dealloc(ptr, layout);
+        }
+    }
+
+    #[test]
+    fn message_buffer_truncates_at_char_boundary() {
+        let mut message = MessageBuffer::default();
+        write!(message, "{}", "a".repeat(254)).unwrap();
+        write!(message, "é").unwrap();
+
+        let terminated = message.terminated();
+        assert_eq!(terminated.len(), 255);
+        assert_eq!(terminated[254], 0);
+    }
+}
@@ -0,0 +1,47 @@
+//! C exports describing the library version.
+//!
+//! [`Version`] is returned by value, so its layout must match the generated header exactly.
+//! See `tests/c_abi.rs`, which checks this against the C compiler.
+
+/// Version of the library, so callers can check that they loaded a compatible build.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Version {
+    /// Major version. Changes when the C API breaks.
+    pub major: u32,
+    /// Minor version.
+    pub minor: u32,
+    /// Patch version.
+    pub patch: u32,
+    /// `true` for pre-release versions such as `1.0.0-beta.1`.
+    pub pre_release: bool,
+}
+
+/// Returns the version of the library, as set in `Cargo.toml`.
+// Not wrapped in `ffi_guard`, as it only reads constants and can't panic.
+#[no_mangle]
+pub extern "C" fn {{crate_name}}_version() -> Version {
+    Version {
+        major: parse(env!("CARGO_PKG_VERSION_MAJOR")),
+        minor: parse(env!("CARGO_PKG_VERSION_MINOR")),
+        patch: parse(env!("CARGO_PKG_VERSION_PATCH")),
+        pre_release: !env!("CARGO_PKG_VERSION_PRE").is_empty(),
+    }
+}
+
+fn parse(component: &str) -> u32 {
+    component.parse().unwrap_or(u32::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn version_matches_manifest() {
+        let version = {{crate_name}}_version();
+        let version = format!("{}.{}.{}", version.major, version.minor, version.patch);
+        assert!(env!("CARGO_PKG_VERSION").starts_with(&version));
+    }
+}
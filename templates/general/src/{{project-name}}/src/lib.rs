@@ -1,22 +1,118 @@
 #![doc = include_str!(concat!("../", env!("CARGO_PKG_README")))]
-{%- if no-std-by-default %}
+{%- if std-by-default or no_std-by-default %}
 #![no_std]
-{%- endif %}
-{%- if std-by-default %}
-#![no_std]
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(any(feature = "std", test))]
 extern crate std;
 {%- endif %}
+{%- if std %}
+{%- if build_c_libs or build_wasm %}
+// The exports import heap types from `alloc`, so they also work in `no_std` crates.
+#[cfg({% if build_c_libs and build_wasm %}any(feature = "c-exports", feature = "wasm"){% else %}feature = "{% if build_c_libs %}c-exports{% else %}wasm{% endif %}"{% endif %})]
+extern crate alloc;
+{%- endif %}
+{%- endif %}
 {%- if build_c_libs %}
 #[cfg(feature = "c-exports")]
 pub mod exports;
 {%- endif %}
+{%- if build_wasm %}
+#[cfg(feature = "wasm")]
+pub mod wasm;
+{%- endif %}
+{%- if std-by-default or no_std-by-default %}
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+{%- endif %}
+
+/// Adds two numbers together.
+{%- if tracing %}
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", ret))]
+{%- endif %}
+pub fn add(left: u64, right: u64) -> u64 {
+    left + right
+}
+
+/// Adds two numbers together, returning `None` if the sum doesn't fit in 64 bits.
+///
+/// Use this instead of [`add`] for untrusted input, as [`add`] panics on overflow in debug builds.
+{%- if tracing %}
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", ret))]
+{%- endif %}
+pub fn checked_add(left: u64, right: u64) -> Option<u64> {
+    left.checked_add(right)
+}
+
+/// Adds `value` to every number in `values`, returning the sums in a new [`Vec`].
+{%- if std-by-default or no_std-by-default %}
+///
+/// Needs a heap, so it's only available with the `alloc` feature.
+#[cfg(feature = "alloc")]
+{%- endif %}
+pub fn add_to_all(values: &[u64], value: u64) -> Vec<u64> {
+    values.iter().map(|&left| add(left, value)).collect()
+}
+
+/// A simple counter, used as an example of a stateful object.
+#[derive(Debug, Default)]
+pub struct Counter {
+    value: u64,
+}
+
+impl Counter {
+    /// Creates a new counter starting at zero.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Increments the counter, returning the new value.
+{%- if tracing %}
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "trace", skip(self), ret)
+    )]
+{%- endif %}
+    pub fn increment(&mut self) -> u64 {
+        self.value += 1;
+        self.value
+    }
+
+    /// Returns the current value of the counter.
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+}
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
     #[test]
     fn it_works() {
-        assert_eq!(2 + 2, 4);
+        assert_eq!(add(2, 2), 4);
     }
-}
 
+    #[test]
+    fn checked_add_reports_overflow() {
+        assert_eq!(checked_add(2, 2), Some(4));
+        assert_eq!(checked_add(u64::MAX, 1), None);
+    }
+
+    #[test]
+{%- if std-by-default or no_std-by-default %}
+    #[cfg(feature = "alloc")]
+{%- endif %}
+    fn add_to_all_adds_to_each_value() {
+        assert_eq!(add_to_all(&[1, 2, 3], 10), [11, 12, 13]);
+    }
+
+    #[test]
+    fn counter_increments() {
+        let mut counter = Counter::new();
+        assert_eq!(counter.increment(), 1);
+        assert_eq!(counter.increment(), 2);
+        assert_eq!(counter.value(), 2);
+    }
+}
@@ -0,0 +1,116 @@
+//! JavaScript exports for browsers and Node.js, made with `wasm-bindgen`.
+//!
+{%- if build_c_libs %}
+//! They mirror the C exports in `exports.rs`, but failures throw a JavaScript `Error` instead
+//! of returning a status, and [`WasmCounter`] becomes the `Counter` class, released by the
+//! garbage collector or `counter.free()`.
+{%- else %}
+//! Failures throw a JavaScript `Error`, and [`WasmCounter`] becomes the `Counter` class,
+//! released by the garbage collector or `counter.free()`.
+{%- endif %}
+//!
+//! The npm package is built from `src/bindings/wasm` with `wasm-pack`. The tests also run
+//! under `wasmtime`, see `.cargo/config.toml`:
+//!
+//! ```bash
+//! cargo test --lib --features wasm --target wasm32-wasip1
+//! ```
+
+use crate::Counter;
+use alloc::format;
+use alloc::string::String;
+use wasm_bindgen::prelude::*;
+
+/// Error thrown to JavaScript as an `Error` with this message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WasmError(String);
+
+impl From<WasmError> for JsValue {
+    fn from(error: WasmError) -> Self {
+        JsError::new(&error.0).into()
+    }
+}
+
+/// Adds two numbers together, throwing if the result overflows.
+#[wasm_bindgen(js_name = checkedAdd)]
+pub fn checked_add(left: u64, right: u64) -> Result<u64, WasmError> {
+    crate::checked_add(left, right).ok_or_else(|| WasmError("integer overflow".into()))
+}
+
+/// Creates a greeting for `name`.
+#[wasm_bindgen]
+pub fn greet(name: &str) -> String {
+    format!("Hello, {name}!")
+}
+
+/// Sums all bytes in `data`.
+#[wasm_bindgen(js_name = sumBytes)]
+pub fn sum_bytes(data: &[u8]) -> u64 {
+    data.iter().map(|&byte| u64::from(byte)).sum()
+}
+
+/// Returns the version of the library, as set in `Cargo.toml`.
+#[wasm_bindgen]
+pub fn version() -> String {
+    env!("CARGO_PKG_VERSION").into()
+}
+
+/// A simple counter, exported to JavaScript as the `Counter` class.
+#[wasm_bindgen(js_name = Counter)]
+#[derive(Debug, Default)]
+pub struct WasmCounter(Counter);
+
+#[wasm_bindgen(js_class = Counter)]
+impl WasmCounter {
+    /// Creates a new counter starting at zero.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Increments the counter, returning the new value.
+    pub fn increment(&mut self) -> u64 {
+        self.0.increment()
+    }
+
+    /// The current value of the counter.
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> u64 {
+        self.0.value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_add_reports_overflow() {
+        assert_eq!(checked_add(2, 2), Ok(4));
+        let overflow = WasmError("integer overflow".into());
+        assert_eq!(checked_add(u64::MAX, 1), Err(overflow));
+    }
+
+    #[test]
+    fn greet_includes_name() {
+        assert_eq!(greet("JavaScript"), "Hello, JavaScript!");
+    }
+
+    #[test]
+    fn sum_bytes_adds_every_byte() {
+        assert_eq!(sum_bytes(&[]), 0);
+        assert_eq!(sum_bytes(&[1, 2, 255]), 258);
+    }
+
+    #[test]
+    fn counter_increments() {
+        let mut counter = WasmCounter::new();
+        assert_eq!(counter.increment(), 1);
+        assert_eq!(counter.value(), 1);
+    }
+
+    #[test]
+    fn version_matches_manifest() {
+        assert_eq!(version(), env!("CARGO_PKG_VERSION"));
+    }
+}
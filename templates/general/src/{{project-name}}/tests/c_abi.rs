@@ -0,0 +1,303 @@
+//! Checks the generated C and C++ headers against the library.
+//!
+//! - Layout: every `#[repr(C)]` type must have the same size, alignment and field offsets
+//!   in Rust and C. This only compiles C code, so it also runs when cross compiling.
+{%- if std or std-by-default %}
+//! - Smoke tests: the programs in `tests/c_abi` are compiled against the headers,
+//!   linked to the `cdylib` and run.
+{%- endif %}
+{%- if std-by-default or no_std-by-default %}
+//! - `no_std` smoke test: `smoke_no_std.c` is linked to a static library built without `std`,
+//!   with the `no-std-runtime` feature.
+{%- endif %}
+//!
+//! # Running
+//! ```bash
+//! cargo test --features c-exports --test c_abi
+//! ```
+
+// Only `generate_header` is used here.
+#[allow(dead_code)]
+#[path = "../build/bindings.rs"]
+mod bindings;
+
+use bindings::generate_header;
+use core::mem::{align_of, offset_of, size_of};
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use {{crate_name}}::exports::error::FfiStatus;
+{%- if std-by-default or no_std-by-default %}
+use {{crate_name}}::exports::hooks::FfiHooks;
+{%- endif %}
+{%- if tracing %}
+use {{crate_name}}::exports::logging::FfiLogLevel;
+{%- endif %}
+use {{crate_name}}::exports::version::Version;
+
+const TARGET: &str = env!("C_ABI_TARGET");
+const HOST: &str = env!("C_ABI_HOST");
+
+/// Layout of a `#[repr(C)]` type as seen by Rust.
+struct Layout {
+    name: &'static str,
+    size: usize,
+    align: usize,
+    fields: Vec<(&'static str, usize)>,
+}
+
+macro_rules! layout {
+    ($ty:ident $({ $($field:ident),* $(,)? })?) => {
+        Layout {
+            name: stringify!($ty),
+            size: size_of::<$ty>(),
+            align: align_of::<$ty>(),
+            fields: vec![$($((stringify!($field), offset_of!($ty, $field))),*)?],
+        }
+    };
+}
+
+/// Every `#[repr(C)]` type exported to C.
+/// [`every_header_type_is_checked`] fails until new types are added here.
+fn layouts() -> Vec<Layout> {
+    vec![
+        layout!(FfiStatus),
+{%- if tracing %}
+        layout!(FfiLogLevel),
+{%- endif %}
+{%- if std-by-default or no_std-by-default %}
+        layout!(FfiHooks { alloc, free, panic }),
+{%- endif %}
+        layout!(Version {
+            major,
+            minor,
+            patch,
+            pre_release
+        }),
+    ]
+}
+
+#[test]
+fn every_header_type_is_checked() {
+    let header = out_dir().join("types_c.h");
+    generate_header("cbindgen_c.toml", &header);
+    let header = fs::read_to_string(header).unwrap();
+
+    // Types with a body, e.g. `typedef struct Version {`. Opaque types have none.
+    let in_header: BTreeSet<&str> = header
+        .lines()
+        .filter_map(|line| line.strip_prefix("typedef "))
+        .filter_map(|line| line.strip_suffix(" {"))
+        .filter_map(|line| line.split_once(' '))
+        .filter(|(kind, _)| ["struct", "enum", "union"].contains(kind))
+        .map(|(_, name)| name)
+        .collect();
+    let checked: BTreeSet<&str> = layouts().iter().map(|layout| layout.name).collect();
+
+    let missing: Vec<_> = in_header.difference(&checked).collect();
+    assert!(
+        missing.is_empty(),
+        "add these types to `layouts()` to check their layout: {missing:?}"
+    );
+}
+
+#[test]
+fn c_layouts_match_rust() {
+    let out_dir = out_dir();
+    generate_header("cbindgen_c.toml", &out_dir.join("bindings_c.h"));
+
+    let mut source = String::from("#include <stddef.h>\n#include \"bindings_c.h\"\n\n");
+    for layout in layouts() {
+        let name = layout.name;
+        static_assert(&mut source, format!("sizeof({name}) == {}", layout.size));
+        static_assert(&mut source, format!("_Alignof({name}) == {}", layout.align));
+
+        for (field, offset) in layout.fields {
+            // cbindgen renames fields to PascalCase, see `rename_fields` in the config.
+            let field = pascal_case(field);
+            static_assert(
+                &mut source,
+                format!("offsetof({name}, {field}) == {offset}"),
+            );
+        }
+    }
+
+    let file = out_dir.join("layout.c");
+    fs::write(&file, source).unwrap();
+    if let Err(error) = build(false)
+        .std("c11")
+        .file(&file)
+        .try_compile("c_abi_layout")
+    {
+        panic!("Rust and C disagree on the layout of exported types:\n{error}");
+    }
+}
+{%- if std or std-by-default %}
+
+#[test]
+#[cfg(unix)]
+fn c_header_compiles_and_links() {
+    run_smoke_test(
+        "smoke.c",
+        "bindings_c.h",
+        "cbindgen_c.toml",
+        false,
+        cdylib_link_args,
+    );
+}
+
+#[test]
+#[cfg(unix)]
+fn cpp_header_compiles_and_links() {
+    run_smoke_test(
+        "smoke.cpp",
+        "bindings_cpp.hpp",
+        "cbindgen_cpp.toml",
+        true,
+        cdylib_link_args,
+    );
+}
+{%- endif %}
+{%- if std-by-default or no_std-by-default %}
+
+#[test]
+#[cfg(unix)]
+fn no_std_staticlib_links() {
+    run_smoke_test(
+        "smoke_no_std.c",
+        "bindings_c.h",
+        "cbindgen_c.toml",
+        false,
+        staticlib_link_args,
+    );
+}
+{%- endif %}
+
+/// Compiles `source` against a freshly generated `header`, links it with the arguments
+/// returned by `link_args` and runs it.
+#[cfg(unix)]
+fn run_smoke_test(
+    source: &str,
+    header: &str,
+    config: &str,
+    cpp: bool,
+    link_args: fn() -> Vec<String>,
+) {
+    use std::process::Command;
+
+    if TARGET != HOST {
+        eprintln!("skipping C ABI smoke test: cannot run {TARGET} binaries on {HOST}");
+        return;
+    }
+
+    let out_dir = out_dir();
+    generate_header(config, &out_dir.join(header));
+
+    let exe = out_dir.join(source.replace('.', "_"));
+    let status = build(cpp)
+        .get_compiler()
+        .to_command()
+        .arg(
+            Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("tests/c_abi")
+                .join(source),
+        )
+        .arg("-I")
+        .arg(&out_dir)
+        .arg("-o")
+        .arg(&exe)
+        .args(link_args())
+        .status()
+        .expect("failed to run the C compiler");
+    assert!(status.success(), "failed to compile {source}");
+
+    // cargo adds `target/<profile>` to the library path, which may hold a stale copy of the
+    // library, so only use the rpath set above.
+    let status = Command::new(&exe)
+        .env_remove("LD_LIBRARY_PATH")
+        .status()
+        .unwrap();
+    assert!(status.success(), "{source} failed with {status}");
+}
+
+/// Configures the C or C++ compiler for the target the tests were built for.
+fn build(cpp: bool) -> cc::Build {
+    let mut build = cc::Build::new();
+    build
+        .cpp(cpp)
+        .target(TARGET)
+        .host(HOST)
+        .opt_level(0)
+        .out_dir(out_dir())
+        .include(out_dir())
+        .cargo_metadata(false);
+    build
+}
+
+fn out_dir() -> PathBuf {
+    let out_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("c_abi");
+    fs::create_dir_all(&out_dir).unwrap();
+    out_dir
+}
+{%- if std or std-by-default %}
+
+/// Links the `cdylib`, which cargo builds next to the test executable.
+///
+/// Only `cargo build` copies it up to `target/<profile>`, so that copy may be stale.
+#[cfg(unix)]
+fn cdylib_link_args() -> Vec<String> {
+    let exe = std::env::current_exe().unwrap();
+    let lib_dir = exe.parent().unwrap().display().to_string();
+    vec![
+        format!("-L{lib_dir}"),
+        "-l{{crate_name}}".into(),
+        format!("-Wl,-rpath,{lib_dir}"),
+    ]
+}
+{%- endif %}
+{%- if std-by-default or no_std-by-default %}
+
+/// Builds the static library without `std` and links it.
+///
+/// It needs `panic = "abort"`, so it's built with the release profile, in its own target
+/// directory to not wait on the lock held by the outer build.
+#[cfg(unix)]
+fn staticlib_link_args() -> Vec<String> {
+    use std::process::Command;
+
+    let target_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("no_std");
+    let status = Command::new(env!("CARGO"))
+        .args(["rustc", "--release", "--no-default-features"])
+        .args(["--features", "no-std-runtime", "--crate-type", "staticlib"])
+        .arg("--manifest-path")
+        .arg(Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml"))
+        .arg("--target-dir")
+        .arg(&target_dir)
+        .status()
+        .expect("failed to run cargo");
+    assert!(
+        status.success(),
+        "failed to build the no_std static library"
+    );
+
+    let library = target_dir.join("release/lib{{crate_name}}.a");
+    vec![library.display().to_string()]
+}
+{%- endif %}
+
+fn static_assert(source: &mut String, condition: String) {
+    writeln!(source, "_Static_assert({condition}, \"{condition}\");").unwrap();
+}
+
+fn pascal_case(name: &str) -> String {
+    name.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars.next().map_or(String::new(), |first| {
+                first.to_uppercase().chain(chars).collect()
+            })
+        })
+        .collect()
+}
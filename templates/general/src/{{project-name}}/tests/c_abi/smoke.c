@@ -0,0 +1,46 @@
+#include <stdio.h>
+#include "bindings_c.h"
+{%- if tracing %}
+
+static int log_messages = 0;
+
+static void count_log_message(FfiLogLevel level, const char *message, uintptr_t len) {
+    (void)level;
+    if (message[len] == '\0') {
+        log_messages++;
+    }
+}
+{%- endif %}
+
+int main(void) {
+    if (it_works() != 1) {
+        fprintf(stderr, "it_works() returned an unexpected value\n");
+        return 1;
+    }
+
+    uint64_t sum = 0;
+    if ({{crate_name}}_checked_add(2, 2, &sum) != FfiStatus_Ok || sum != 4) {
+        fprintf(stderr, "{{crate_name}}_checked_add(2, 2) failed\n");
+        return 1;
+    }
+{%- if tracing %}
+
+    if ({{crate_name}}_set_log_callback(count_log_message) != FfiStatus_Ok) {
+        fprintf(stderr, "{{crate_name}}_set_log_callback failed\n");
+        return 1;
+    }
+
+    // `Counter::increment` logs its return value at the trace level.
+    Counter *counter = {{crate_name}}_counter_new();
+    uint64_t value = 0;
+    {{crate_name}}_counter_increment(counter, &value);
+    {{crate_name}}_counter_free(counter);
+    {{crate_name}}_set_log_callback(NULL);
+    if (log_messages == 0) {
+        fprintf(stderr, "the log callback was not called\n");
+        return 1;
+    }
+{%- endif %}
+
+    return 0;
+}
@@ -0,0 +1,45 @@
+#include <cstdio>
+#include "bindings_cpp.hpp"
+{%- if tracing %}
+
+static int log_messages = 0;
+
+static void count_log_message({{crate_name}}::FfiLogLevel, const char *message, uintptr_t len) {
+    if (message[len] == '\0') {
+        log_messages++;
+    }
+}
+{%- endif %}
+
+int main() {
+    if ({{crate_name}}::it_works() != 1) {
+        std::fprintf(stderr, "it_works() returned an unexpected value\n");
+        return 1;
+    }
+
+    uint64_t sum = 0;
+    if ({{crate_name}}::{{crate_name}}_checked_add(2, 2, &sum) != {{crate_name}}::FfiStatus::Ok || sum != 4) {
+        std::fprintf(stderr, "{{crate_name}}_checked_add(2, 2) failed\n");
+        return 1;
+    }
+{%- if tracing %}
+
+    if ({{crate_name}}::{{crate_name}}_set_log_callback(count_log_message) != {{crate_name}}::FfiStatus::Ok) {
+        std::fprintf(stderr, "{{crate_name}}_set_log_callback failed\n");
+        return 1;
+    }
+
+    // `Counter::increment` logs its return value at the trace level.
+    {{crate_name}}::Counter *counter = {{crate_name}}::{{crate_name}}_counter_new();
+    uint64_t value = 0;
+    {{crate_name}}::{{crate_name}}_counter_increment(counter, &value);
+    {{crate_name}}::{{crate_name}}_counter_free(counter);
+    {{crate_name}}::{{crate_name}}_set_log_callback(nullptr);
+    if (log_messages == 0) {
+        std::fprintf(stderr, "the log callback was not called\n");
+        return 1;
+    }
+{%- endif %}
+
+    return 0;
+}
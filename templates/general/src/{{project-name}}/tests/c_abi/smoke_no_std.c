@@ -0,0 +1,64 @@
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+#include "bindings_c.h"
+
+// The `no_std` static library has no allocator or panic handler, so the host provides them.
+
+static void *host_alloc(uintptr_t size, uintptr_t align) {
+    void *ptr = NULL;
+    // `posix_memalign` needs at least pointer alignment.
+    if (align < sizeof(void *)) {
+        align = sizeof(void *);
+    }
+    return posix_memalign(&ptr, align, size) == 0 ? ptr : NULL;
+}
+
+static void host_free(void *ptr, uintptr_t size, uintptr_t align) {
+    (void)size;
+    (void)align;
+    free(ptr);
+}
+
+static void host_panic(const char *message, uintptr_t len) {
+    fprintf(stderr, "the library panicked: %.*s\n", (int)len, message);
+    abort();
+}
+
+int main(void) {
+    FfiHooks hooks = { host_alloc, host_free, host_panic };
+    if ({{crate_name}}_init_runtime(&hooks) != FfiStatus_Ok) {
+        fprintf(stderr, "{{crate_name}}_init_runtime failed\n");
+        return 1;
+    }
+
+    uint64_t sum = 0;
+    if ({{crate_name}}_checked_add(2, 2, &sum) != FfiStatus_Ok || sum != 4) {
+        fprintf(stderr, "{{crate_name}}_checked_add(2, 2) failed\n");
+        return 1;
+    }
+
+    // Errors and strings are allocated with the hooks above.
+    if ({{crate_name}}_checked_add(UINT64_MAX, 1, &sum) != FfiStatus_Error
+        || {{crate_name}}_last_error_length() == 0) {
+        fprintf(stderr, "{{crate_name}}_checked_add did not report the overflow\n");
+        return 1;
+    }
+
+    char *greeting = {{crate_name}}_greet("C");
+    if (greeting == NULL || strstr(greeting, "C") == NULL) {
+        fprintf(stderr, "{{crate_name}}_greet(\"C\") failed\n");
+        return 1;
+    }
+    {{crate_name}}_free_string(greeting);
+
+    Counter *counter = {{crate_name}}_counter_new();
+    uint64_t value = 0;
+    if ({{crate_name}}_counter_increment(counter, &value) != FfiStatus_Ok || value != 1) {
+        fprintf(stderr, "{{crate_name}}_counter_increment failed\n");
+        return 1;
+    }
+    {{crate_name}}_counter_free(counter);
+
+    return 0;
+}
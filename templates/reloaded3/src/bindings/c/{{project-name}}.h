@@ -0,0 +1,202 @@
+#ifdef _MSC_VER
+    /* MSVC can't pack a single struct, so `#[repr(packed)]` types are not supported there.
+       There's no global `#pragma pack`, so other structs keep the default C alignment, which
+       matches `#[repr(C)]`. */
+    #define PACKED
+#else
+    #define PACKED __attribute__((packed))
+#endif
+
+
+#ifndef {{crate_name}}
+#define {{crate_name}}
+
+/* Warning, this file is autogenerated by cbindgen. Don't modify this manually. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Version of the entry points and [`LoaderApi`].
+ * Bump it on breaking changes, along with `api-version` in `package.toml`.
+ */
+#define MOD_API_VERSION 1
+
+/**
+ * Status code returned by fallible exports.
+ */
+typedef enum FfiStatus {
+  /**
+   * The operation completed successfully.
+   */
+  FfiStatus_Ok = 0,
+  /**
+   * The operation failed. Check the last error message for details.
+   */
+  FfiStatus_Error = 1,
+  /**
+   * A required pointer argument was null.
+   */
+  FfiStatus_NullPointer = 2,
+  /**
+   * The provided buffer was too small to hold the result.
+   */
+  FfiStatus_BufferTooSmall = 3,
+  /**
+   * The library panicked. Check the last error message for details.
+   */
+  FfiStatus_Panic = 4,
+  /**
+   * A handle was invalid, e.g. it was already freed.
+   */
+  FfiStatus_InvalidHandle = 5,
+  /**
+   * A string argument was not valid UTF-8.
+   */
+  FfiStatus_InvalidUtf8 = 6,
+} FfiStatus;
+
+/**
+ * Severity of a log message, from most to least severe.
+ */
+typedef enum FfiLogLevel {
+  /**
+   * Something failed.
+   */
+  FfiLogLevel_Error = 1,
+  /**
+   * Something unexpected happened, but the mod recovered.
+   */
+  FfiLogLevel_Warn = 2,
+  /**
+   * High level progress.
+   */
+  FfiLogLevel_Info = 3,
+  /**
+   * Details useful when debugging.
+   */
+  FfiLogLevel_Debug = 4,
+  /**
+   * Very verbose details.
+   */
+  FfiLogLevel_Trace = 5,
+} FfiLogLevel;
+
+/**
+ * Functions the loader provides to the mod. Copied by [`reloaded_mod_start`].
+ */
+typedef struct LoaderApi {
+  /**
+   * The [`MOD_API_VERSION`] the loader implements.
+   */
+  uint32_t ApiVersion;
+  /**
+   * The loader's state, passed back to every function below.
+   */
+  void *Context;
+  /**
+   * Writes `len` bytes of UTF-8 text, followed by a null terminator, to the loader's log.
+   * May be called from any thread, until the mod is unloaded.
+   */
+  void (*Log)(void *context, enum FfiLogLevel level, const char *message, uintptr_t len);
+} LoaderApi;
+
+/**
+ * Version of the library, so callers can check that they loaded a compatible build.
+ */
+typedef struct Version {
+  /**
+   * Major version. Changes when the C API breaks.
+   */
+  uint32_t Major;
+  /**
+   * Minor version.
+   */
+  uint32_t Minor;
+  /**
+   * Patch version.
+   */
+  uint32_t Patch;
+  /**
+   * `true` for pre-release versions such as `1.0.0-beta.1`.
+   */
+  bool PreRelease;
+} Version;
+
+/**
+ * Returns the [`MOD_API_VERSION`] the mod was built for.
+ */
+ uint32_t reloaded_mod_api_version(void) ;
+
+/**
+ * Starts the mod. Called once, after the library is loaded.
+ *
+ * # Parameters
+ *
+ * - `loader`: The loader's functions, copied by the mod.
+ *
+ * # Returns
+ *
+ * - [`FfiStatus::Ok`] if the mod started.
+ * - [`FfiStatus::Error`] if the mod failed to start, was already started,
+ *   or `loader` has a different [`MOD_API_VERSION`].
+ * - [`FfiStatus::NullPointer`] if `loader` is null.
+ * - [`FfiStatus::Panic`] if the mod panicked.
+ *
+ * # Safety
+ *
+ * `loader` must be null or valid for reads.
+ * Its functions must stay valid until [`reloaded_mod_unload`] returns.
+ */
+ enum FfiStatus reloaded_mod_start(const struct LoaderApi *loader) ;
+
+/**
+ * Stops the mod. Called before the library is unloaded.
+ *
+ * # Returns
+ *
+ * - [`FfiStatus::Ok`] if the mod was stopped.
+ * - [`FfiStatus::Error`] if the mod was not started.
+ * - [`FfiStatus::Panic`] if the mod panicked.
+ */
+ enum FfiStatus reloaded_mod_unload(void) ;
+
+/**
+ * Returns the length of the last error message in bytes, including the null terminator.
+ *
+ * # Returns
+ *
+ * `0` if no error has been recorded on the current thread.
+ */
+ uintptr_t {{crate_name}}_last_error_length(void) ;
+
+/**
+ * Copies the last error message, including the null terminator, into `buffer`.
+ *
+ * # Parameters
+ *
+ * - `buffer`: Buffer to write the message to.
+ * - `buffer_len`: Length of `buffer` in bytes. Use [`{{crate_name}}_last_error_length`] to size it.
+ *
+ * # Returns
+ *
+ * - [`FfiStatus::Ok`] if the message was copied. An empty string is written if there is no error.
+ * - [`FfiStatus::NullPointer`] if `buffer` is null.
+ * - [`FfiStatus::BufferTooSmall`] if `buffer_len` is smaller than the message length.
+ *
+ * # Safety
+ *
+ * `buffer` must be valid for writes of `buffer_len` bytes.
+ */
+ enum FfiStatus {{crate_name}}_last_error_message(char *buffer, uintptr_t bufferLen) ;
+
+/**
+ * Returns the version of the library, as set in `Cargo.toml`.
+ */
+ struct Version {{crate_name}}_version(void) ;
+
+#endif  /* {{crate_name}} */
+
+/* Text to put at the end of the generated file */
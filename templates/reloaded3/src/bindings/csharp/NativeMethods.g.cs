@@ -0,0 +1,211 @@
+// <auto-generated>
+// This code is generated by csbindgen.
+// DON'T CHANGE THIS DIRECTLY.
+// </auto-generated>
+#pragma warning disable CS8500
+#pragma warning disable CS8981
+using System;
+using System.Runtime.InteropServices;
+
+
+namespace {{crate_name}}.Net.Sys
+{
+    public static unsafe partial class NativeMethods
+    {
+        const string __DllName = "{{crate_name}}";
+
+
+
+
+
+        /// <summary>
+        ///  Returns the [`MOD_API_VERSION`] the mod was built for.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "reloaded_mod_api_version", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern uint reloaded_mod_api_version();
+
+        /// <summary>
+        ///  Starts the mod. Called once, after the library is loaded.
+        ///
+        ///  # Parameters
+        ///
+        ///  - `loader`: The loader's functions, copied by the mod.
+        ///
+        ///  # Returns
+        ///
+        ///  - [`FfiStatus::Ok`] if the mod started.
+        ///  - [`FfiStatus::Error`] if the mod failed to start, was already started,
+        ///    or `loader` has a different [`MOD_API_VERSION`].
+        ///  - [`FfiStatus::NullPointer`] if `loader` is null.
+        ///  - [`FfiStatus::Panic`] if the mod panicked.
+        ///
+        ///  # Safety
+        ///
+        ///  `loader` must be null or valid for reads.
+        ///  Its functions must stay valid until [`reloaded_mod_unload`] returns.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "reloaded_mod_start", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern FfiStatus reloaded_mod_start(LoaderApi* loader);
+
+        /// <summary>
+        ///  Stops the mod. Called before the library is unloaded.
+        ///
+        ///  # Returns
+        ///
+        ///  - [`FfiStatus::Ok`] if the mod was stopped.
+        ///  - [`FfiStatus::Error`] if the mod was not started.
+        ///  - [`FfiStatus::Panic`] if the mod panicked.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "reloaded_mod_unload", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern FfiStatus reloaded_mod_unload();
+
+        /// <summary>
+        ///  Returns the length of the last error message in bytes, including the null terminator.
+        ///
+        ///  # Returns
+        ///
+        ///  `0` if no error has been recorded on the current thread.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "{{crate_name}}_last_error_length", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern nuint {{crate_name}}_last_error_length();
+
+        /// <summary>
+        ///  Copies the last error message, including the null terminator, into `buffer`.
+        ///
+        ///  # Parameters
+        ///
+        ///  - `buffer`: Buffer to write the message to.
+        ///  - `buffer_len`: Length of `buffer` in bytes. Use [`{{crate_name}}_last_error_length`] to size it.
+        ///
+        ///  # Returns
+        ///
+        ///  - [`FfiStatus::Ok`] if the message was copied. An empty string is written if there is no error.
+        ///  - [`FfiStatus::NullPointer`] if `buffer` is null.
+        ///  - [`FfiStatus::BufferTooSmall`] if `buffer_len` is smaller than the message length.
+        ///
+        ///  # Safety
+        ///
+        ///  `buffer` must be valid for writes of `buffer_len` bytes.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "{{crate_name}}_last_error_message", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern FfiStatus {{crate_name}}_last_error_message(byte* buffer, nuint buffer_len);
+
+        /// <summary>
+        ///  Returns the version of the library, as set in `Cargo.toml`.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "{{crate_name}}_version", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Version {{crate_name}}_version();
+
+
+    }
+
+    /// <summary>
+    ///  Functions the loader provides to the mod. Copied by [`reloaded_mod_start`].
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    public unsafe partial struct LoaderApi
+    {
+        /// <summary>
+        ///  The [`MOD_API_VERSION`] the loader implements.
+        /// </summary>
+        public uint api_version;
+        /// <summary>
+        ///  The loader's state, passed back to every function below.
+        /// </summary>
+        public void* context;
+        /// <summary>
+        ///  Writes `len` bytes of UTF-8 text, followed by a null terminator, to the loader's log.
+        ///  May be called from any thread, until the mod is unloaded.
+        /// </summary>
+        public delegate* unmanaged[Cdecl]<void*, FfiLogLevel, byte*, nuint, void> log;
+    }
+
+    /// <summary>
+    ///  Version of the library, so callers can check that they loaded a compatible build.
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    public unsafe partial struct Version
+    {
+        /// <summary>
+        ///  Major version. Changes when the C API breaks.
+        /// </summary>
+        public uint major;
+        /// <summary>
+        ///  Minor version.
+        /// </summary>
+        public uint minor;
+        /// <summary>
+        ///  Patch version.
+        /// </summary>
+        public uint patch;
+        /// <summary>
+        ///  `true` for pre-release versions such as `1.0.0-beta.1`.
+        /// </summary>
+        [MarshalAs(UnmanagedType.U1)] public bool pre_release;
+    }
+
+
+    /// <summary>
+    ///  Severity of a log message, from most to least severe.
+    /// </summary>
+    public enum FfiLogLevel : uint
+    {
+        /// <summary>
+        ///  Something failed.
+        /// </summary>
+        Error = 1,
+        /// <summary>
+        ///  Something unexpected happened, but the mod recovered.
+        /// </summary>
+        Warn = 2,
+        /// <summary>
+        ///  High level progress.
+        /// </summary>
+        Info = 3,
+        /// <summary>
+        ///  Details useful when debugging.
+        /// </summary>
+        Debug = 4,
+        /// <summary>
+        ///  Very verbose details.
+        /// </summary>
+        Trace = 5,
+    }
+
+    /// <summary>
+    ///  Status code returned by fallible exports.
+    /// </summary>
+    public enum FfiStatus : uint
+    {
+        /// <summary>
+        ///  The operation completed successfully.
+        /// </summary>
+        Ok = 0,
+        /// <summary>
+        ///  The operation failed. Check the last error message for details.
+        /// </summary>
+        Error = 1,
+        /// <summary>
+        ///  A required pointer argument was null.
+        /// </summary>
+        NullPointer = 2,
+        /// <summary>
+        ///  The provided buffer was too small to hold the result.
+        /// </summary>
+        BufferTooSmall = 3,
+        /// <summary>
+        ///  The library panicked. Check the last error message for details.
+        /// </summary>
+        Panic = 4,
+        /// <summary>
+        ///  A handle was invalid, e.g. it was already freed.
+        /// </summary>
+        InvalidHandle = 5,
+        /// <summary>
+        ///  A string argument was not valid UTF-8.
+        /// </summary>
+        InvalidUtf8 = 6,
+    }
+
+
+}
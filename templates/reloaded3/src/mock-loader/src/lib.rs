@@ -0,0 +1,230 @@
+//! Loads a mod's library and calls its entry points the way the Reloaded-III loader does,
+//! so mods can be tested on any platform without the real loader.
+//!
+//! The types here mirror `src/exports/entry.rs` in the mod, as a loader written against the
+//! C header would. `tests/mock_loader.rs` in the mod checks that both sides agree.
+//!
+//! # Example
+//! ```no_run
+//! use mock_loader::{LoadedMod, Package};
+//! use std::path::Path;
+//!
+//! let package = Package::load(Path::new("{{project-name}}/package.toml"))?;
+//! let loaded = LoadedMod::load(&package, &package.library_path(Path::new("target/debug")))?;
+//! for message in loaded.unload()? {
+//!     println!("{message:?}");
+//! }
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use libloading::{Library, Symbol};
+use serde::Deserialize;
+use std::error::Error;
+use std::ffi::{c_char, c_void};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, PoisonError};
+
+/// Version of the entry points and the functions passed to them, which this loader implements.
+pub const API_VERSION: u32 = 1;
+
+/// A mod's `package.toml`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Package {
+    /// Unique ID of the mod.
+    pub id: String,
+    /// Name shown to users.
+    pub name: String,
+    /// Author of the mod.
+    pub author: String,
+    /// Short description of the mod.
+    pub summary: String,
+    /// Version of the mod.
+    pub version: String,
+    /// How to load the mod.
+    pub entry: Entry,
+}
+
+/// The `[entry]` table of `package.toml`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct Entry {
+    /// The mod's library, without the platform's prefix and extension.
+    pub library: String,
+    /// The [`API_VERSION`] the mod needs.
+    pub api_version: u32,
+}
+
+impl Package {
+    /// Loads a `package.toml`.
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let text = fs::read_to_string(path)
+            .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
+        toml::from_str(&text)
+            .map_err(|err| format!("invalid package {}: {err}", path.display()).into())
+    }
+
+    /// Path of the mod's library in `dir`, e.g. `dir/libmy_mod.so` on Linux.
+    pub fn library_path(&self, dir: &Path) -> PathBuf {
+        dir.join(libloading::library_filename(&self.entry.library))
+    }
+}
+
+/// Severity of a log message, from most to least severe.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FfiLogLevel {
+    Error = 1,
+    Warn = 2,
+    Info = 3,
+    Debug = 4,
+    Trace = 5,
+}
+
+/// Functions the loader passes to `reloaded_mod_start`.
+#[repr(C)]
+struct LoaderApi {
+    api_version: u32,
+    context: *mut c_void,
+    log: unsafe extern "C" fn(
+        context: *mut c_void,
+        level: FfiLogLevel,
+        message: *const c_char,
+        len: usize,
+    ),
+}
+
+/// `FfiStatus` returned by the entry points. Only `Ok` matters here.
+type FfiStatus = i32;
+const FFI_STATUS_OK: FfiStatus = 0;
+
+type ApiVersionFn = unsafe extern "C" fn() -> u32;
+type StartFn = unsafe extern "C" fn(loader: *const LoaderApi) -> FfiStatus;
+type UnloadFn = unsafe extern "C" fn() -> FfiStatus;
+
+/// A message the mod wrote to the loader's log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogMessage {
+    pub level: FfiLogLevel,
+    pub message: String,
+}
+
+type Log = Mutex<Vec<LogMessage>>;
+
+/// A started mod. It's unloaded when dropped, unless [`LoadedMod::unload`] was called.
+pub struct LoadedMod {
+    library: Option<Library>,
+    // Boxed, as the mod keeps a pointer to it until it's unloaded.
+    log: Box<Log>,
+}
+
+impl LoadedMod {
+    /// Loads the library at `path`, checks that it implements the API version in `package`,
+    /// and starts it.
+    pub fn load(package: &Package, path: &Path) -> Result<Self, Box<dyn Error>> {
+        if package.entry.api_version != API_VERSION {
+            return Err(format!(
+                "{} needs API version {}, but the loader implements {API_VERSION}",
+                package.id, package.entry.api_version
+            )
+            .into());
+        }
+
+        // SAFETY: Loading a library runs its initializers, so this trusts the mod.
+        let library = unsafe { Library::new(path) }
+            .map_err(|err| format!("failed to load {}: {err}", path.display()))?;
+
+        let api_version =
+            unsafe { symbol::<ApiVersionFn>(&library, "reloaded_mod_api_version")?() };
+        if api_version != package.entry.api_version {
+            return Err(format!(
+                "the library was built for API version {api_version}, but package.toml says {}",
+                package.entry.api_version
+            )
+            .into());
+        }
+
+        let log = Box::new(Log::default());
+        let api = LoaderApi {
+            api_version: API_VERSION,
+            context: (&*log as *const Log).cast_mut().cast(),
+            log: log_message,
+        };
+        let status = unsafe { symbol::<StartFn>(&library, "reloaded_mod_start")?(&api) };
+        check(status, "reloaded_mod_start")?;
+
+        Ok(Self {
+            library: Some(library),
+            log,
+        })
+    }
+
+    /// Returns the messages the mod logged so far.
+    pub fn logs(&self) -> Vec<LogMessage> {
+        self.log
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Unloads the mod, returning everything it logged, including while unloading.
+    pub fn unload(mut self) -> Result<Vec<LogMessage>, Box<dyn Error>> {
+        self.unload_library()?;
+        Ok(self.logs())
+    }
+
+    fn unload_library(&mut self) -> Result<(), Box<dyn Error>> {
+        let Some(library) = self.library.take() else {
+            return Ok(());
+        };
+
+        let status = unsafe { symbol::<UnloadFn>(&library, "reloaded_mod_unload")?() };
+        check(status, "reloaded_mod_unload")?;
+        library.close()?;
+        Ok(())
+    }
+}
+
+impl Drop for LoadedMod {
+    fn drop(&mut self) {
+        let _ = self.unload_library();
+    }
+}
+
+/// Looks up an entry point.
+///
+/// # Safety
+///
+/// `T` must match the entry point's signature.
+unsafe fn symbol<'lib, T>(
+    library: &'lib Library,
+    name: &str,
+) -> Result<Symbol<'lib, T>, Box<dyn Error>> {
+    library
+        .get(name)
+        .map_err(|err| format!("the library doesn't export {name}: {err}").into())
+}
+
+fn check(status: FfiStatus, entry_point: &str) -> Result<(), Box<dyn Error>> {
+    if status == FFI_STATUS_OK {
+        Ok(())
+    } else {
+        Err(format!("{entry_point} failed with status {status}").into())
+    }
+}
+
+/// `LoaderApi::log`, which records the message in the [`Log`] passed as `context`.
+unsafe extern "C" fn log_message(
+    context: *mut c_void,
+    level: FfiLogLevel,
+    message: *const c_char,
+    len: usize,
+) {
+    let log = &*context.cast::<Log>();
+    let message = std::slice::from_raw_parts(message.cast::<u8>(), len);
+    let message = String::from_utf8_lossy(message).into_owned();
+    log.lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .push(LogMessage { level, message });
+}
@@ -0,0 +1,52 @@
+//! Loads a mod like the Reloaded-III loader, then unloads it, printing everything it logged.
+//!
+//! # Running
+//! ```bash
+//! cargo build
+//! cargo run -p mock-loader -- {{project-name}}/package.toml
+//! ```
+
+use clap::Parser;
+use mock_loader::{LoadedMod, Package};
+use std::error::Error;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+/// Loads a mod like the Reloaded-III loader, then unloads it.
+#[derive(Debug, Parser)]
+#[command(about, long_about = None)]
+struct Cli {
+    /// The mod's `package.toml`.
+    package: PathBuf,
+
+    /// Directory with the mod's built library.
+    #[arg(long, default_value = "target/debug")]
+    library_dir: PathBuf,
+}
+
+fn main() -> ExitCode {
+    match run(&Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(cli: &Cli) -> Result<(), Box<dyn Error>> {
+    let package = Package::load(&cli.package)?;
+    let path = package.library_path(&cli.library_dir);
+    println!(
+        "Loading {} {} from {}",
+        package.name,
+        package.version,
+        path.display()
+    );
+
+    let loaded = LoadedMod::load(&package, &path)?;
+    for message in loaded.unload()? {
+        println!("[{:?}] {}", message.level, message.message);
+    }
+    Ok(())
+}
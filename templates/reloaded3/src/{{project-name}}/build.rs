@@ -0,0 +1,27 @@
+#[cfg(feature = "generate-bindings")]
+#[path = "build/bindings.rs"]
+mod bindings;
+
+fn main() {
+    // Lets `tests/bindings.rs` skip the check when cross compiling.
+    let target = std::env::var("TARGET").unwrap();
+    let host = std::env::var("HOST").unwrap();
+    println!("cargo:rustc-env=C_ABI_TARGET={target}");
+    println!("cargo:rustc-env=C_ABI_HOST={host}");
+
+    #[cfg(feature = "generate-bindings")]
+    generate_bindings();
+}
+
+/// Updates the committed bindings in `../bindings`.
+/// `tests/bindings.rs` fails if they are out of date.
+#[cfg(feature = "generate-bindings")]
+fn generate_bindings() {
+    bindings::generate(std::path::Path::new("../bindings"));
+
+    // Printing `rerun-if-changed` disables the default of rerunning on any change.
+    println!("cargo:rerun-if-changed=src");
+    println!("cargo:rerun-if-changed=build");
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=../../.github/cbindgen_c.toml");
+}
@@ -0,0 +1,6 @@
+//! The C exports: the [`entry`] points the loader calls, plus anything the mod exports
+//! for other mods. Add those as new files in `exports`, and to `build/bindings.rs`.
+
+pub mod entry;
+pub mod error;
+pub mod version;
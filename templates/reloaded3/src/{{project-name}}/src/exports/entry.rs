@@ -0,0 +1,170 @@
+//! Entry points the Reloaded-III loader looks up by name after loading the mod's library.
+//!
+//! The loader calls them in order:
+//! 1. [`reloaded_mod_api_version`], and refuses to load the mod if it doesn't match its own.
+//! 2. [`reloaded_mod_start`] once, with the [`LoaderApi`] the mod uses to talk to the loader.
+//! 3. [`reloaded_mod_unload`] before it unloads the library.
+//!
+//! `src/mock-loader` calls them the same way, see `tests/mock_loader.rs`.
+//! The mod itself is [`Mod`], these only pass its state around.
+
+use super::error::{ffi_guard, set_last_error, to_status, FfiStatus};
+use crate::host::Host;
+use crate::Mod;
+use std::ffi::{c_char, c_void};
+use std::sync::Mutex;
+
+/// Version of the entry points and [`LoaderApi`].
+/// Bump it on breaking changes, along with `api-version` in `package.toml`.
+pub const MOD_API_VERSION: u32 = 1;
+
+/// Severity of a log message, from most to least severe.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FfiLogLevel {
+    /// Something failed.
+    Error = 1,
+    /// Something unexpected happened, but the mod recovered.
+    Warn = 2,
+    /// High level progress.
+    Info = 3,
+    /// Details useful when debugging.
+    Debug = 4,
+    /// Very verbose details.
+    Trace = 5,
+}
+
+/// Functions the loader provides to the mod. Copied by [`reloaded_mod_start`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct LoaderApi {
+    /// The [`MOD_API_VERSION`] the loader implements.
+    pub api_version: u32,
+    /// The loader's state, passed back to every function below.
+    pub context: *mut c_void,
+    /// Writes `len` bytes of UTF-8 text, followed by a null terminator, to the loader's log.
+    /// May be called from any thread, until the mod is unloaded.
+    pub log: unsafe extern "C" fn(
+        context: *mut c_void,
+        level: FfiLogLevel,
+        message: *const c_char,
+        len: usize,
+    ),
+}
+
+/// The running mod, between [`reloaded_mod_start`] and [`reloaded_mod_unload`].
+static MOD: Mutex<Option<Mod>> = Mutex::new(None);
+
+/// Returns the [`MOD_API_VERSION`] the mod was built for.
+#[no_mangle]
+pub extern "C" fn reloaded_mod_api_version() -> u32 {
+    MOD_API_VERSION
+}
+
+/// Starts the mod. Called once, after the library is loaded.
+///
+/// # Parameters
+///
+/// - `loader`: The loader's functions, copied by the mod.
+///
+/// # Returns
+///
+/// - [`FfiStatus::Ok`] if the mod started.
+/// - [`FfiStatus::Error`] if the mod failed to start, was already started,
+///   or `loader` has a different [`MOD_API_VERSION`].
+/// - [`FfiStatus::NullPointer`] if `loader` is null.
+/// - [`FfiStatus::Panic`] if the mod panicked.
+///
+/// # Safety
+///
+/// `loader` must be null or valid for reads.
+/// Its functions must stay valid until [`reloaded_mod_unload`] returns.
+#[no_mangle]
+pub unsafe extern "C" fn reloaded_mod_start(loader: *const LoaderApi) -> FfiStatus {
+    ffi_guard(FfiStatus::Panic, || {
+        if loader.is_null() {
+            return FfiStatus::NullPointer;
+        }
+
+        let loader = *loader;
+        if loader.api_version != MOD_API_VERSION {
+            set_last_error(format_args!(
+                "loader implements API version {}, but the mod needs {MOD_API_VERSION}",
+                loader.api_version
+            ));
+            return FfiStatus::Error;
+        }
+
+        let mut running = MOD.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        if running.is_some() {
+            set_last_error("the mod is already started");
+            return FfiStatus::Error;
+        }
+
+        to_status(Mod::start(Host::new(loader)).map(|started| *running = Some(started)))
+    })
+}
+
+/// Stops the mod. Called before the library is unloaded.
+///
+/// # Returns
+///
+/// - [`FfiStatus::Ok`] if the mod was stopped.
+/// - [`FfiStatus::Error`] if the mod was not started.
+/// - [`FfiStatus::Panic`] if the mod panicked.
+#[no_mangle]
+pub extern "C" fn reloaded_mod_unload() -> FfiStatus {
+    ffi_guard(FfiStatus::Panic, || {
+        let running = MOD
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .take();
+        match running {
+            Some(running) => {
+                running.unload();
+                FfiStatus::Ok
+            }
+            None => {
+                set_last_error("the mod is not started");
+                FfiStatus::Error
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ptr::{null, null_mut};
+
+    unsafe extern "C" fn ignore_log(_: *mut c_void, _: FfiLogLevel, _: *const c_char, _: usize) {}
+
+    fn loader(api_version: u32) -> LoaderApi {
+        LoaderApi {
+            api_version,
+            context: null_mut(),
+            log: ignore_log,
+        }
+    }
+
+    #[test]
+    fn start_and_unload() {
+        let loader = loader(MOD_API_VERSION);
+        assert_eq!(unsafe { reloaded_mod_start(&loader) }, FfiStatus::Ok);
+        assert_eq!(unsafe { reloaded_mod_start(&loader) }, FfiStatus::Error);
+
+        assert_eq!(reloaded_mod_unload(), FfiStatus::Ok);
+        assert_eq!(reloaded_mod_unload(), FfiStatus::Error);
+    }
+
+    #[test]
+    fn start_rejects_invalid_loader() {
+        assert_eq!(
+            unsafe { reloaded_mod_start(null()) },
+            FfiStatus::NullPointer
+        );
+
+        let loader = loader(MOD_API_VERSION + 1);
+        assert_eq!(unsafe { reloaded_mod_start(&loader) }, FfiStatus::Error);
+    }
+}
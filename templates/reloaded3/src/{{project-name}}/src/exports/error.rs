@@ -0,0 +1,244 @@
+//! Error reporting across the C boundary.
+//!
+//! Fallible exports return an [`FfiStatus`]. When the status is not [`FfiStatus::Ok`],
+//! a human readable message is stored for the calling thread and can be retrieved with
+//! [`{{crate_name}}_last_error_length`] and [`{{crate_name}}_last_error_message`].
+//!
+//! Wrap the body of every export in `ffi_guard` so that a panic is reported as an
+//! error instead of unwinding into the caller.
+{%- if std-by-default or no_std-by-default %}
+//!
+//! Without `std`, there is one last error shared by all threads, and panics are not caught.
+{%- endif %}
+
+use alloc::ffi::CString;
+use alloc::string::ToString;
+use core::ffi::c_char;
+use core::fmt::Display;
+
+/// Status code returned by fallible exports.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FfiStatus {
+    /// The operation completed successfully.
+    Ok = 0,
+    /// The operation failed. Check the last error message for details.
+    Error = 1,
+    /// A required pointer argument was null.
+    NullPointer = 2,
+    /// The provided buffer was too small to hold the result.
+    BufferTooSmall = 3,
+    /// The library panicked. Check the last error message for details.
+    Panic = 4,
+    /// A handle was invalid, e.g. it was already freed.
+    InvalidHandle = 5,
+    /// A string argument was not valid UTF-8.
+    InvalidUtf8 = 6,
+}
+
+{%- if std-by-default or no_std-by-default %}
+#[cfg(any(feature = "std", test))]
+{%- endif %}
+std::thread_local! {
+    static LAST_ERROR: core::cell::RefCell<Option<CString>> =
+        const { core::cell::RefCell::new(None) };
+}
+{%- if std-by-default or no_std-by-default %}
+
+#[cfg(not(any(feature = "std", test)))]
+static LAST_ERROR: spin::Mutex<Option<CString>> = spin::Mutex::new(None);
+{%- endif %}
+
+/// Runs `f` with the last error message of the current thread.
+{%- if std-by-default or no_std-by-default %}
+#[cfg(any(feature = "std", test))]
+{%- endif %}
+fn with_last_error<R>(f: impl FnOnce(&mut Option<CString>) -> R) -> R {
+    LAST_ERROR.with(|last| f(&mut last.borrow_mut()))
+}
+{%- if std-by-default or no_std-by-default %}
+
+/// Runs `f` with the last error message, which all threads share without `std`.
+#[cfg(not(any(feature = "std", test)))]
+fn with_last_error<R>(f: impl FnOnce(&mut Option<CString>) -> R) -> R {
+    f(&mut LAST_ERROR.lock())
+}
+{%- endif %}
+
+/// Stores `error` as the last error message for the current thread.
+pub(crate) fn set_last_error(error: impl Display) {
+    // Interior nulls would truncate the message on the C side, so drop them.
+    let mut message = error.to_string();
+    message.retain(|c| c != '\0');
+    let message = CString::new(message).ok();
+    with_last_error(|last| *last = message);
+}
+
+/// Converts a [`Result`] into an [`FfiStatus`], recording the error message on failure.
+pub(crate) fn to_status<E: Display>(result: Result<(), E>) -> FfiStatus {
+    match result {
+        Ok(()) => FfiStatus::Ok,
+        Err(error) => {
+            set_last_error(error);
+            FfiStatus::Error
+        }
+    }
+}
+
+/// Runs `f`, returning `sentinel` if it panics.
+///
+/// The panic message is recorded as the last error, so the caller gets a clean error
+/// instead of unwinding across `extern "C"`, which would abort the process.
+///
+/// Release builds use `panic = "abort"`, in which case this has no effect.
+///
+/// # Example
+///
+/// ```ignore
+/// #[no_mangle]
+/// pub extern "C" fn my_export() -> FfiStatus {
+///     ffi_guard(FfiStatus::Panic, || to_status(do_work()))
+/// }
+/// ```
+{%- if std-by-default or no_std-by-default %}
+#[cfg(any(feature = "std", test))]
+{%- endif %}
+pub(crate) fn ffi_guard<T>(sentinel: T, f: impl FnOnce() -> T) -> T {
+    // The caller can't observe Rust state after a panic, only the sentinel,
+    // so asserting unwind safety here is fine.
+    match std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)) {
+        Ok(value) => value,
+        Err(payload) => {
+            set_last_error(format_args!("panic: {}", panic_message(&*payload)));
+            sentinel
+        }
+    }
+}
+{%- if std-by-default or no_std-by-default %}
+
+/// Runs `f`. Panics can't be caught without `std`, so they go to the `#[panic_handler]`.
+#[cfg(not(any(feature = "std", test)))]
+pub(crate) fn ffi_guard<T>(_sentinel: T, f: impl FnOnce() -> T) -> T {
+    f()
+}
+{%- endif %}
+
+{%- if std-by-default or no_std-by-default %}
+#[cfg(any(feature = "std", test))]
+{%- endif %}
+fn panic_message(payload: &(dyn core::any::Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<alloc::string::String>() {
+        message
+    } else {
+        "unknown panic"
+    }
+}
+
+/// Returns the length of the last error message in bytes, including the null terminator.
+///
+/// # Returns
+///
+/// `0` if no error has been recorded on the current thread.
+// Not wrapped in `ffi_guard`, which would replace the message being read on a panic.
+// It only reads the message, which can't panic.
+#[no_mangle]
+pub extern "C" fn {{crate_name}}_last_error_length() -> usize {
+    with_last_error(|last| {
+        last.as_ref()
+            .map_or(0, |message| message.as_bytes_with_nul().len())
+    })
+}
+
+/// Copies the last error message, including the null terminator, into `buffer`.
+///
+/// # Parameters
+///
+/// - `buffer`: Buffer to write the message to.
+/// - `buffer_len`: Length of `buffer` in bytes. Use [`{{crate_name}}_last_error_length`] to size it.
+///
+/// # Returns
+///
+/// - [`FfiStatus::Ok`] if the message was copied. An empty string is written if there is no error.
+/// - [`FfiStatus::NullPointer`] if `buffer` is null.
+/// - [`FfiStatus::BufferTooSmall`] if `buffer_len` is smaller than the message length.
+///
+/// # Safety
+///
+/// `buffer` must be valid for writes of `buffer_len` bytes.
+// Not wrapped in `ffi_guard`, for the same reason as `{{crate_name}}_last_error_length`.
+#[no_mangle]
+pub unsafe extern "C" fn {{crate_name}}_last_error_message(
+    buffer: *mut c_char,
+    buffer_len: usize,
+) -> FfiStatus {
+    if buffer.is_null() {
+        return FfiStatus::NullPointer;
+    }
+
+    with_last_error(|last| {
+        let message = last
+            .as_ref()
+            .map_or(&[0u8][..], |message| message.as_bytes_with_nul());
+        if message.len() > buffer_len {
+            return FfiStatus::BufferTooSmall;
+        }
+
+        core::ptr::copy_nonoverlapping(message.as_ptr(), buffer.cast::<u8>(), message.len());
+        FfiStatus::Ok
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::ffi::CStr;
+
+    fn last_error_message(buffer: &mut [c_char]) -> FfiStatus {
+        unsafe { {{crate_name}}_last_error_message(buffer.as_mut_ptr(), buffer.len()) }
+    }
+
+    #[test]
+    fn to_status_records_error_message() {
+        assert_eq!(to_status(Err("something went wrong")), FfiStatus::Error);
+        let length = {{crate_name}}_last_error_length();
+        assert_eq!(length, "something went wrong".len() + 1);
+
+        let mut buffer = [0 as c_char; 64];
+        assert_eq!(last_error_message(&mut buffer), FfiStatus::Ok);
+
+        let message = unsafe { CStr::from_ptr(buffer.as_ptr()) };
+        assert_eq!(message.to_str().unwrap(), "something went wrong");
+    }
+
+    #[test]
+    fn last_error_message_reports_small_buffer() {
+        set_last_error("too long for the buffer");
+
+        let mut buffer = [0 as c_char; 4];
+        assert_eq!(last_error_message(&mut buffer), FfiStatus::BufferTooSmall);
+    }
+
+    #[test]
+    fn ffi_guard_returns_sentinel_on_panic() {
+        let status = ffi_guard(FfiStatus::Panic, || -> FfiStatus { panic!("boom") });
+        assert_eq!(status, FfiStatus::Panic);
+
+        let mut buffer = [0 as c_char; 64];
+        assert_eq!(last_error_message(&mut buffer), FfiStatus::Ok);
+
+        let message = unsafe { CStr::from_ptr(buffer.as_ptr()) };
+        assert_eq!(message.to_str().unwrap(), "panic: boom");
+    }
+
+    #[test]
+    fn no_error_reports_empty_message() {
+        with_last_error(|last| *last = None);
+        assert_eq!({{crate_name}}_last_error_length(), 0);
+
+        let mut buffer = [1 as c_char; 1];
+        assert_eq!(last_error_message(&mut buffer), FfiStatus::Ok);
+        assert_eq!(buffer[0], 0);
+    }
+}
@@ -0,0 +1,31 @@
+//! The loader's functions, wrapped for use from Rust.
+
+use crate::exports::entry::{FfiLogLevel, LoaderApi};
+use std::ffi::CString;
+
+/// Lets [`Mod`](crate::Mod) call into the loader that started it.
+#[derive(Debug)]
+pub struct Host {
+    api: LoaderApi,
+}
+
+// SAFETY: The loader's functions may be called from any thread, see `LoaderApi`.
+unsafe impl Send for Host {}
+unsafe impl Sync for Host {}
+
+impl Host {
+    /// Wraps the functions passed to `reloaded_mod_start`, which stay valid until the mod is unloaded.
+    pub(crate) fn new(api: LoaderApi) -> Self {
+        Self { api }
+    }
+
+    /// Writes `message` to the loader's log.
+    pub fn log(&self, level: FfiLogLevel, message: &str) {
+        // Interior nulls would truncate the message on the loader's side, so drop them.
+        let mut message = message.to_owned();
+        message.retain(|c| c != '\0');
+        let len = message.len();
+        let message = CString::new(message).unwrap_or_default();
+        unsafe { (self.api.log)(self.api.context, level, message.as_ptr(), len) };
+    }
+}
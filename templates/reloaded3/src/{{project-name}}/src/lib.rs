@@ -0,0 +1,36 @@
+#![doc = include_str!(concat!("../", env!("CARGO_PKG_README")))]
+// The exports shared with the general template import heap types from `alloc`.
+extern crate alloc;
+
+pub mod exports;
+pub mod host;
+
+use exports::entry::FfiLogLevel;
+use host::Host;
+
+/// The mod's state, from when the loader starts it until it's unloaded.
+///
+/// Add your mod's code here. The C exports in [`exports`] call into it.
+#[derive(Debug)]
+pub struct Mod {
+    host: Host,
+}
+
+impl Mod {
+    /// Called when the loader starts the mod. Returning an error stops the mod from loading.
+    pub fn start(host: Host) -> Result<Self, String> {
+        host.log(
+            FfiLogLevel::Info,
+            concat!(env!("CARGO_PKG_NAME"), " started"),
+        );
+        Ok(Self { host })
+    }
+
+    /// Called before the loader unloads the mod. Undo any changes the mod made here.
+    pub fn unload(self) {
+        self.host.log(
+            FfiLogLevel::Info,
+            concat!(env!("CARGO_PKG_NAME"), " unloaded"),
+        );
+    }
+}
@@ -0,0 +1,64 @@
+//! Checks that the committed bindings in `src/bindings` match the C exports.
+//!
+//! The bindings are regenerated into a temporary directory and compared with the committed files,
+//! so changes to the C ABI show up as a diff in pull requests.
+//!
+//! # Running
+//! ```bash
+//! cargo test --test bindings
+//! ```
+//!
+//! Don't enable the `generate-bindings` feature here, it updates the committed files
+//! before the test runs.
+//!
+//! # Updating
+//! ```bash
+//! cargo build --features generate-bindings
+//! ```
+
+#[path = "../build/bindings.rs"]
+mod bindings;
+
+use similar::TextDiff;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+#[test]
+fn committed_bindings_are_up_to_date() {
+    // The bindings don't depend on the target, and cross doesn't mount the cbindgen configs.
+    if env!("C_ABI_TARGET") != env!("C_ABI_HOST") {
+        eprintln!("skipping bindings check: only runs for the host target");
+        return;
+    }
+
+    let out_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("bindings");
+    let committed_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../bindings");
+
+    let mut diffs = String::new();
+    for file in bindings::generate(&out_dir) {
+        let committed = read_bindings(&committed_dir.join(file));
+        let generated = read_bindings(&out_dir.join(file));
+        if committed != generated {
+            let diff = TextDiff::from_lines(&committed, &generated);
+            let diff = diff
+                .unified_diff()
+                .header(&format!("committed/{file}"), &format!("generated/{file}"))
+                .to_string();
+            writeln!(diffs, "{diff}").unwrap();
+        }
+    }
+
+    assert!(
+        diffs.is_empty(),
+        "The committed bindings are out of date. \
+         Update them with `cargo build --features generate-bindings`.\n\n{diffs}"
+    );
+}
+
+/// Reads a bindings file, treating a missing file as empty.
+fn read_bindings(path: &Path) -> String {
+    let text = fs::read_to_string(path).unwrap_or_default();
+    // Git may check out the committed files with CRLF line endings on Windows.
+    text.replace("\r\n", "\n")
+}
@@ -0,0 +1,59 @@
+//! Loads the mod's `cdylib` with `src/mock-loader`, the way the Reloaded-III loader does.
+//!
+//! # Running
+//! ```bash
+//! cargo test --test mock_loader
+//! ```
+
+use mock_loader::{FfiLogLevel, LoadedMod, LogMessage, Package};
+use std::path::{Path, PathBuf};
+
+fn package() -> Package {
+    Package::load(&Path::new(env!("CARGO_MANIFEST_DIR")).join("package.toml")).unwrap()
+}
+
+/// The `cdylib`, which cargo builds next to the test executable.
+///
+/// Only `cargo build` copies it up to `target/<profile>`, so that copy may be stale.
+fn library_path(package: &Package) -> PathBuf {
+    let exe = std::env::current_exe().unwrap();
+    package.library_path(exe.parent().unwrap())
+}
+
+#[test]
+fn mod_starts_and_unloads() {
+    let package = package();
+    let loaded = LoadedMod::load(&package, &library_path(&package)).unwrap();
+    let started = LogMessage {
+        level: FfiLogLevel::Info,
+        message: concat!(env!("CARGO_PKG_NAME"), " started").into(),
+    };
+    assert_eq!(loaded.logs(), std::slice::from_ref(&started));
+
+    let unloaded = LogMessage {
+        level: FfiLogLevel::Info,
+        message: concat!(env!("CARGO_PKG_NAME"), " unloaded").into(),
+    };
+    assert_eq!(loaded.unload().unwrap(), [started, unloaded]);
+}
+
+#[test]
+fn package_matches_crate() {
+    let package = package();
+    assert_eq!(package.version, env!("CARGO_PKG_VERSION"));
+    assert_eq!(
+        package.entry.api_version,
+        {{crate_name}}::exports::entry::MOD_API_VERSION
+    );
+    assert!(
+        library_path(&package).exists(),
+        "package.toml names the wrong library"
+    );
+}
+
+#[test]
+fn unsupported_api_version_is_rejected() {
+    let mut package = package();
+    package.entry.api_version += 1;
+    assert!(LoadedMod::load(&package, &library_path(&package)).is_err());
+}